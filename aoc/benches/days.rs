@@ -0,0 +1,36 @@
+//! Benchmarks every part of every day against its example input, and against its real input if
+//! there is a dayNN/input.txt.
+
+use aoc::examples;
+use aoc_common::Part;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+
+fn bench_examples(c: &mut Criterion) {
+    // the manifest has the options needed to run each example, e.g. day 9's window size
+    for e in examples::manifest().unwrap() {
+        let text = fs::read_to_string(examples::root().join(examples::example_path(e.day))).unwrap();
+        let solution = e.solution().unwrap();
+        c.bench_function(&format!("day{:02}/part{}/example", e.day, e.part), |b| {
+            b.iter(|| solution.run(e.part, &text).unwrap())
+        });
+    }
+}
+
+fn bench_inputs(c: &mut Criterion) {
+    for solution in aoc::solutions() {
+        let path = examples::root().join(format!("day{:02}/input.txt", solution.day()));
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(_) => continue,
+        };
+        for part in Part::ALL.iter().copied() {
+            c.bench_function(&format!("day{:02}/part{}/input", solution.day(), part), |b| {
+                b.iter(|| solution.run(part, &text).unwrap())
+            });
+        }
+    }
+}
+
+criterion_group!(benches, bench_examples, bench_inputs);
+criterion_main!(benches);
@@ -0,0 +1,90 @@
+//! Timing statistics for the `bench` command.
+
+use aoc_common::{Part, Result, Solution};
+use serde_json::json;
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// Statistics over the run times of one part of a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub day: u32,
+    pub part: Part,
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Runs a part of a solution the given number of times and collects its timings.
+    pub fn measure(solution: &dyn Solution, part: Part, text: &str, iterations: usize) -> Result<Self> {
+        let mut times = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            solution.run(part, text)?;
+            times.push(start.elapsed());
+        }
+        Ok(Stats::from_times(solution.day(), part, times))
+    }
+
+    fn from_times(day: u32, part: Part, mut times: Vec<Duration>) -> Self {
+        assert!(!times.is_empty(), "at least one iteration is required");
+        times.sort();
+        let n = times.len();
+        let median = if n.is_multiple_of(2) {
+            (times[n / 2 - 1] + times[n / 2]) / 2
+        } else {
+            times[n / 2]
+        };
+        let mean = times.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = times.iter()
+            .map(|t| (t.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / n as f64;
+        Stats {
+            day,
+            part,
+            iterations: n,
+            min: times[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        json!({
+            "day": self.day,
+            "part": self.part.number(),
+            "iterations": self.iterations,
+            "min_ms": ms(self.min),
+            "median_ms": ms(self.median),
+            "mean_ms": ms(self.mean),
+            "stddev_ms": ms(self.stddev),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        writeln!(fmt, "Day {} part {} ({} iterations)", self.day, self.part, self.iterations)?;
+        writeln!(fmt, "  min:    {:?}", self.min)?;
+        writeln!(fmt, "  median: {:?}", self.median)?;
+        writeln!(fmt, "  mean:   {:?}", self.mean)?;
+        write!(fmt, "  stddev: {:?}", self.stddev)
+    }
+}
+
+#[test]
+fn test_from_times() {
+    let times = [4, 1, 3, 2].iter().map(|&ms| Duration::from_millis(ms)).collect();
+    let stats = Stats::from_times(1, Part::One, times);
+    assert_eq!(stats.iterations, 4);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.mean, Duration::from_micros(2500));
+    // population standard deviation of 1, 2, 3, 4 is sqrt(1.25)
+    assert!((stats.stddev.as_secs_f64() - 1.25f64.sqrt() / 1000.0).abs() < 1e-9);
+}
@@ -0,0 +1,77 @@
+//! The manifest of expected answers for each day's example.txt, kept in tests/examples.txt.
+
+use aoc_common::{input, solution, Part, Result, Solution};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A single expected answer from the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// The line of the manifest that this came from.
+    pub line: usize,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    /// Options to set on the solution, as `key=value` strings.
+    pub options: Vec<String>,
+}
+
+impl Expected {
+    /// Gets the solution for this example's day with its options set.
+    pub fn solution(&self) -> Result<Box<dyn Solution>> {
+        let mut solution = crate::solution(self.day)
+            .ok_or_else(|| format!("examples.txt:{}: no solution for day {}", self.line, self.day))?;
+        for option in self.options.iter() {
+            solution::configure(solution.as_mut(), option)?;
+        }
+        Ok(solution)
+    }
+}
+
+/// The root of the workspace.
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The path to a day's example input, relative to the workspace root.
+pub fn example_path(day: u32) -> String {
+    format!("day{:02}/example.txt", day)
+}
+
+/// Reads and parses the manifest.
+pub fn manifest() -> Result<Vec<Expected>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples.txt");
+    parse_manifest(&fs::read_to_string(path)?)
+}
+
+/// Parses a manifest, where each line is `day part answer [options...]` and `#` starts a comment.
+pub fn parse_manifest(text: &str) -> Result<Vec<Expected>> {
+    let mut expected = Vec::new();
+    for (line, text) in input::numbered_lines(text) {
+        if text.trim_start().starts_with('#') {
+            continue;
+        }
+        let fields: Vec<_> = text.split_whitespace().collect();
+        if fields.len() < 3 {
+            return Err(format!("examples.txt:{}: expected `day part answer [options...]`", line).into());
+        }
+        expected.push(Expected {
+            line,
+            day: fields[0].parse().map_err(|_| format!("examples.txt:{}: invalid day", line))?,
+            part: fields[1].parse()?,
+            answer: fields[2].to_string(),
+            options: fields[3..].iter().map(ToString::to_string).collect(),
+        });
+    }
+    Ok(expected)
+}
+
+#[test]
+fn test_parse_manifest() {
+    let expected = parse_manifest("# comment\n1 1 514579\n\n9 2 62 window=5\n").unwrap();
+    assert_eq!(expected.len(), 2);
+    assert_eq!(expected[1].line, 4);
+    assert_eq!(expected[1].part, Part::Two);
+    assert_eq!(expected[1].options, vec!["window=5"]);
+    assert!(parse_manifest("1 1\n").is_err());
+}
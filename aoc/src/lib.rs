@@ -0,0 +1,25 @@
+//! Registry of every day's solution.
+
+use aoc_common::Solution;
+
+pub mod examples;
+
+/// Gets every solution, in order of day.
+pub fn solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(day01::Day01::default()),
+        Box::new(day02::Day02::default()),
+        Box::new(day03::Day03::default()),
+        Box::new(day04::Day04::default()),
+        Box::new(day05::Day05::default()),
+        Box::new(day06::Day06::default()),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09::default()),
+    ]
+}
+
+/// Gets the solution for the given day, if there is one.
+pub fn solution(day: u32) -> Option<Box<dyn Solution>> {
+    solutions().into_iter().find(|s| s.day() == day)
+}
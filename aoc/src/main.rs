@@ -0,0 +1,315 @@
+mod bench;
+
+use aoc_common::output::{self, DayReport, Format, PartReport};
+use aoc_common::{input, solution, Part, Result, Solution};
+use bench::Stats;
+use std::fs::File;
+use std::io::{stdin, BufRead, BufReader};
+use std::path::PathBuf;
+
+const USAGE: &str = "\
+usage: aoc run <DAY|all> [--part 1|2] [--input PATH] [--format text|json]
+               [--opt [DAY:]KEY=VALUE]... [--stream]
+       aoc bench <DAY|all> [--iterations N] [--part 1|2] [--input PATH] [--format text|json]
+                 [--opt [DAY:]KEY=VALUE]...
+       aoc list
+
+Runs the solution for DAY. The input is read from PATH, or from standard input if it is not given.
+When running all days, each day's input is read from dayNN/input.txt.
+
+With --format json, a single JSON document is written containing the answers, details and timings
+of each part. When running all days, this is an array with one element per day.
+
+--opt sets a day-specific option, e.g. `aoc run 9 --opt window=5`. The option can be prefixed with
+the day that it is for, e.g. `aoc run all --opt 9:window=5`, which is required when running all
+days.
+
+--stream runs only part 1, reading the input incrementally instead of all at once, for input that
+is too large to hold in memory.
+
+bench runs each part N times (default 100) and reports the minimum, median, mean and standard
+deviation of the run times.";
+
+/// The number of times each part is run by `bench` if no number is given.
+const DEFAULT_ITERATIONS: usize = 100;
+
+fn main() {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!();
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = command.execute() {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+enum Command {
+    Run(RunArgs),
+    Bench(RunArgs),
+    List,
+}
+
+struct RunArgs {
+    /// Which day to run, or `None` to run all of them.
+    day: Option<u32>,
+    /// Which part to run, or `None` to run both of them.
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    format: Format,
+    /// Day-specific options, as `key=value` strings, with the day that each is only for.
+    options: Vec<(Option<u32>, String)>,
+    /// How many times to run each part when benchmarking.
+    iterations: Option<usize>,
+    /// Whether to run part 1 on input that is read incrementally.
+    stream: bool,
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let command = args.next().ok_or("no command given")?;
+        match command.as_str() {
+            "run" => {
+                let run = RunArgs::parse(args)?;
+                if run.iterations.is_some() {
+                    return Err("--iterations can only be used with bench".into());
+                }
+                Ok(Command::Run(run))
+            }
+            "bench" => {
+                let bench = RunArgs::parse(args)?;
+                if bench.stream {
+                    return Err("--stream can only be used with run".into());
+                }
+                Ok(Command::Bench(bench))
+            }
+            "list" => Ok(Command::List),
+            "-h" | "--help" | "help" => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => Err(format!("unknown command {:?}", command).into()),
+        }
+    }
+
+    fn execute(self) -> Result<()> {
+        match self {
+            Command::Run(run) => run.execute(),
+            Command::Bench(run) => run.bench(),
+            Command::List => {
+                for solution in aoc::solutions() {
+                    println!("{}", solution.day());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl RunArgs {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let day = args.next().ok_or("no day given")?;
+        let day = if day == "all" {
+            None
+        } else {
+            Some(day.parse().map_err(|_| format!("invalid day {:?}", day))?)
+        };
+        let mut run = RunArgs {
+            day,
+            part: None,
+            input: None,
+            format: Format::Text,
+            options: Vec::new(),
+            iterations: None,
+            stream: false,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} requires a value", arg));
+            match arg.as_str() {
+                "--part" => run.part = Some(value()?.parse()?),
+                "--input" => run.input = Some(value()?.into()),
+                "--format" => run.format = value()?.parse()?,
+                "--opt" => run.options.push(scoped_option(value()?)?),
+                "--stream" => run.stream = true,
+                "--iterations" => {
+                    let value = value()?;
+                    match value.parse() {
+                        Ok(0) | Err(_) => return Err(format!("invalid number of iterations {:?}", value).into()),
+                        Ok(iterations) => run.iterations = Some(iterations),
+                    }
+                }
+                _ => return Err(format!("unknown argument {:?}", arg).into()),
+            }
+        }
+        if run.day.is_none() && run.input.is_some() {
+            return Err("--input cannot be used when running all days".into());
+        }
+        if let Some((_, option)) = run.options.iter().find(|(day, _)| day.is_none() && run.day.is_none()) {
+            return Err(format!(
+                "--opt {:?} must say which day it is for when running all days, like 9:{}",
+                option, option
+            )
+            .into());
+        }
+        if let Some((Some(day), option)) = run.options.iter().find(|(day, _)| day.is_some_and(|day| aoc::solution(day).is_none())) {
+            return Err(format!("--opt {}:{} is for day {}, which has no solution", day, option, day).into());
+        }
+        if run.stream && run.part == Some(Part::Two) {
+            return Err("--stream can only be used with part 1".into());
+        }
+        Ok(run)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None if self.stream => vec![Part::One],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// Gets the solutions that were asked for with their options set.
+    fn configured(&self) -> Result<Vec<Box<dyn Solution>>> {
+        let solutions = match self.day {
+            Some(day) => vec![aoc::solution(day).ok_or_else(|| format!("no solution for day {}", day))?],
+            None => aoc::solutions(),
+        };
+
+        let mut configured = Vec::new();
+        for mut solution in solutions {
+            let day = solution.day();
+            for (_, option) in self.options.iter().filter(|(only, _)| only.is_none_or(|only| only == day)) {
+                solution::configure(solution.as_mut(), option)?;
+            }
+            configured.push(solution);
+        }
+        Ok(configured)
+    }
+
+    /// Opens the input for a day.
+    fn open_input(&self, day: u32) -> Result<Box<dyn BufRead>> {
+        Ok(match &self.input {
+            Some(path) => Box::new(BufReader::new(File::open(path)?)),
+            None if self.day.is_some() => Box::new(stdin().lock()),
+            None => {
+                let path = format!("day{:02}/input.txt", day);
+                Box::new(BufReader::new(File::open(&path).map_err(|e| format!("{}: {}", path, e))?))
+            }
+        })
+    }
+
+    /// Gets the solutions that were asked for with their options set, along with their inputs.
+    fn solutions(&self) -> Result<Vec<(Box<dyn Solution>, String)>> {
+        self.configured()?
+            .into_iter()
+            .map(|solution| {
+                let text = input::read_all(&mut self.open_input(solution.day())?)?;
+                Ok((solution, text))
+            })
+            .collect()
+    }
+
+    fn execute(self) -> Result<()> {
+        let parts = self.parts();
+        let mut reports = Vec::new();
+        if self.stream {
+            for (i, solution) in self.configured()?.into_iter().enumerate() {
+                if i > 0 && self.format == Format::Text {
+                    println!();
+                }
+                let mut reader = self.open_input(solution.day())?;
+                let run = |_| PartReport::run_streaming(solution.as_ref(), &mut reader);
+                reports.push(run_day(solution.day(), &parts, self.format, run)?);
+            }
+        } else {
+            for (i, (solution, text)) in self.solutions()?.into_iter().enumerate() {
+                if i > 0 && self.format == Format::Text {
+                    println!();
+                }
+                let run = |part| PartReport::run(solution.as_ref(), part, &text);
+                reports.push(run_day(solution.day(), &parts, self.format, run)?);
+                solution.render(&text, &mut output::console(self.format))?;
+            }
+        }
+
+        if self.format == Format::Json {
+            let json = match self.day {
+                Some(_) => reports[0].to_json(),
+                None => reports.iter().map(DayReport::to_json).collect(),
+            };
+            println!("{}", json);
+        }
+        Ok(())
+    }
+
+    fn bench(self) -> Result<()> {
+        let parts = self.parts();
+        let iterations = self.iterations.unwrap_or(DEFAULT_ITERATIONS);
+        let mut all_stats = Vec::new();
+        for (solution, text) in self.solutions()? {
+            for part in parts.iter().copied() {
+                let stats = Stats::measure(solution.as_ref(), part, &text, iterations)?;
+                if self.format == Format::Text {
+                    if !all_stats.is_empty() {
+                        println!();
+                    }
+                    println!("{}", stats);
+                }
+                all_stats.push(stats);
+            }
+            solution.render(&text, &mut output::console(self.format))?;
+        }
+
+        if self.format == Format::Json {
+            let json: serde_json::Value = all_stats.iter().map(Stats::to_json).collect();
+            println!("{}", json);
+        }
+        Ok(())
+    }
+}
+
+/// Splits the day that an option is for from the option, like `9:window=5`.
+fn scoped_option(option: String) -> Result<(Option<u32>, String)> {
+    let key = option.split('=').next().unwrap_or("");
+    match key.find(':') {
+        Some(i) => {
+            let day = key[..i].trim().parse().map_err(|_| format!("invalid day in option {:?}", option))?;
+            Ok((Some(day), option[i + 1..].to_string()))
+        }
+        None => Ok((None, option)),
+    }
+}
+
+/// Runs the given parts of a day's solution, printing each one as it finishes if the format is
+/// text.
+fn run_day<F>(day: u32, parts: &[Part], format: Format, mut run: F) -> Result<DayReport>
+where
+    F: FnMut(Part) -> Result<PartReport>,
+{
+    let mut report = DayReport {
+        day,
+        parts: Vec::new(),
+    };
+    if format == Format::Text {
+        println!("Day {}", report.day);
+        println!();
+    }
+    for (i, part) in parts.iter().copied().enumerate() {
+        let part = run(part)?;
+        if format == Format::Text {
+            if i > 0 {
+                println!();
+            }
+            println!("{}", part);
+            println!("Finished in {:?}", part.elapsed);
+        }
+        report.parts.push(part);
+    }
+    Ok(report)
+}
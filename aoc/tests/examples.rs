@@ -0,0 +1,41 @@
+//! Runs every day's example.txt and checks the answers against the ones listed in examples.txt.
+
+use aoc::examples;
+use std::fs;
+
+#[test]
+fn test_examples() {
+    let expected = examples::manifest().unwrap();
+
+    // every day should have at least one example checked
+    for solution in aoc::solutions() {
+        assert!(
+            expected.iter().any(|e| e.day == solution.day()),
+            "day {} has no entry in examples.txt",
+            solution.day()
+        );
+    }
+
+    let mut failures = Vec::new();
+    for e in expected.iter() {
+        let path = examples::example_path(e.day);
+        let text = fs::read_to_string(examples::root().join(&path))
+            .unwrap_or_else(|err| panic!("{}: {}", path, err));
+        let actual = e.solution()
+            .and_then(|solution| solution.run(e.part, &text))
+            .map(|answer| answer.value.to_string())
+            .unwrap_or_else(|err| format!("error: {}", err));
+        if actual != e.answer {
+            failures.push(format!("day {} part {} ({}):\n- {}\n+ {}", e.day, e.part, path, e.answer, actual));
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} examples gave the wrong answer (- expected, + actual):\n\n{}\n",
+            failures.len(),
+            expected.len(),
+            failures.join("\n\n")
+        );
+    }
+}
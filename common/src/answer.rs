@@ -0,0 +1,140 @@
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+/// A value produced by a solution, either as an answer or as a detail about the answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    Text(String),
+    List(Vec<Value>),
+    /// Named values, in order, like a JSON object.
+    Map(Vec<(String, Value)>),
+}
+
+impl From<i64> for Value {
+    fn from(other: i64) -> Self {
+        Value::Int(other)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(other: i32) -> Self {
+        Value::Int(other.into())
+    }
+}
+
+impl From<u32> for Value {
+    fn from(other: u32) -> Self {
+        Value::Int(other.into())
+    }
+}
+
+impl From<usize> for Value {
+    /// Numbers too large for an `Int` are kept exactly as text rather than wrapping around.
+    fn from(other: usize) -> Self {
+        match i64::try_from(other) {
+            Ok(i) => Value::Int(i),
+            Err(_) => Value::Text(other.to_string()),
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(other: &str) -> Self {
+        Value::Text(other.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(other: String) -> Self {
+        Value::Text(other)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(other: Vec<T>) -> Self {
+        Value::List(other.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Value::Int(i) => write!(fmt, "{}", i),
+            Value::Text(s) => write!(fmt, "{}", s),
+            Value::List(items) => {
+                write!(fmt, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(fmt, ", ")?;
+                    }
+                    write!(fmt, "{}", item)?;
+                }
+                write!(fmt, "]")
+            }
+            Value::Map(entries) => {
+                write!(fmt, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(fmt, ", ")?;
+                    }
+                    write!(fmt, "{}: {}", key, value)?;
+                }
+                write!(fmt, "}}")
+            }
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: Value,
+    /// Extra information about how the answer was reached, in the order that it was added.
+    pub details: Vec<(String, Value)>,
+}
+
+impl Answer {
+    pub fn new(value: impl Into<Value>) -> Self {
+        Answer {
+            value: value.into(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Adds a detail to this answer.
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.details.push((key.into(), value.into()));
+        self
+    }
+
+    /// Gets the detail with the given key.
+    pub fn detail(&self, key: &str) -> Option<&Value> {
+        self.details
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        for (key, value) in self.details.iter() {
+            writeln!(fmt, "{}: {}", key, value)?;
+        }
+        write!(fmt, "Answer: {}", self.value)
+    }
+}
+
+#[test]
+fn test_display() {
+    let answer = Answer::new(514579)
+        .with("operands", vec![1721, 299])
+        .with("note", "ok");
+    assert_eq!(answer.to_string(), "operands: [1721, 299]\nnote: ok\nAnswer: 514579");
+    assert_eq!(answer.detail("note"), Some(&Value::Text("ok".to_string())));
+    assert_eq!(answer.detail("missing"), None);
+    assert_eq!(Value::from(usize::MAX), Value::Text(usize::MAX.to_string()));
+    let map = Value::Map(vec![("slope".to_string(), "3/1".into()), ("hits".to_string(), 7.into())]);
+    assert_eq!(map.to_string(), "{slope: 3/1, hits: 7}");
+}
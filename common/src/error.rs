@@ -0,0 +1,106 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A place in the puzzle input. Both the line and column are 1-based, and columns count characters
+/// rather than bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize) -> Self {
+        Position { line, column }
+    }
+
+    /// Gets the position of the given byte offset into a line of text.
+    pub fn in_line(line: usize, text: &str, offset: usize) -> Self {
+        Position {
+            line,
+            column: text[..offset].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// An error that occurred while parsing puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Some text did not have the form that was expected.
+    Syntax {
+        at: Position,
+        /// The offending text.
+        text: String,
+        /// A description of what was expected instead, e.g. "a rule like `1-3 a: abcde`".
+        expected: String,
+    },
+    /// Some text had the right form, but could not be converted to a number.
+    Number {
+        at: Position,
+        /// The offending text.
+        text: String,
+        /// Why it could not be converted.
+        reason: String,
+    },
+}
+
+impl ParseError {
+    pub fn syntax(at: Position, text: impl Into<String>, expected: impl Into<String>) -> Self {
+        ParseError::Syntax {
+            at,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    pub fn number(at: Position, text: impl Into<String>, reason: impl Display) -> Self {
+        ParseError::Number {
+            at,
+            text: text.into(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Where in the input the error occurred.
+    pub fn position(&self) -> Position {
+        match self {
+            ParseError::Syntax { at, .. } | ParseError::Number { at, .. } => *at,
+        }
+    }
+
+    /// The text that caused the error.
+    pub fn text(&self) -> &str {
+        match self {
+            ParseError::Syntax { text, .. } | ParseError::Number { text, .. } => text,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseError::Syntax { at, text, expected } => {
+                write!(fmt, "{}: expected {}, but found {:?}", at, expected, text)
+            }
+            ParseError::Number { at, text, reason } => {
+                write!(fmt, "{}: invalid number {:?} ({})", at, text, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[test]
+fn test_display() {
+    let err = ParseError::syntax(Position::in_line(4, "jmp +1", 0), "jmp +1", "an instruction");
+    assert_eq!(err.to_string(), r#"line 4, column 1: expected an instruction, but found "jmp +1""#);
+    let err = ParseError::number(Position::in_line(2, "ü 12x", 3), "12x", "invalid digit found in string");
+    assert_eq!(err.position(), Position::new(2, 3));
+    assert_eq!(err.to_string(), r#"line 2, column 3: invalid number "12x" (invalid digit found in string)"#);
+}
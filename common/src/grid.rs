@@ -0,0 +1,132 @@
+//! A rectangular grid of cells, for puzzles that take place on a map.
+
+use crate::error::ParseError;
+use crate::input;
+use std::fmt::{self, Display, Formatter};
+
+/// A rectangular grid of cells, indexed by `(x, y)` with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The cells, one row after another.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Makes a grid from its rows, or returns `None` if they are not all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the grid has no cells at all.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Gets a cell, or `None` if it is outside of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Gets a cell mutably, or `None` if it is outside of the grid.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Gets a cell as if the grid repeated forever to the left and right, or `None` if `y` is
+    /// outside of the grid.
+    pub fn get_wrapping(&self, x: isize, y: usize) -> Option<&T> {
+        if self.width == 0 {
+            return None;
+        }
+        self.get(x.rem_euclid(self.width as isize) as usize, y)
+    }
+
+    /// Iterates over the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a size of 0, but then there are no cells to split up anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Makes a grid of the same size by converting every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid of characters from the non-empty lines of the input.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let rows = input::char_grid(text)?;
+        // char_grid already checks that every row is the same width
+        Ok(Grid::from_rows(rows).unwrap())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    /// Writes each row on its own line, with no separators between cells.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(fmt)?;
+            }
+            for cell in row {
+                write!(fmt, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() {
+    let grid = Grid::parse("#..\r\n.#.\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(1, 1), Some(&'#'));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(grid.get_wrapping(3, 0), Some(&'#'));
+    assert_eq!(grid.get_wrapping(-2, 1), Some(&'#'));
+    assert_eq!(grid.get_wrapping(0, 2), None);
+    assert_eq!(grid.to_string(), "#..\n.#.");
+    assert_eq!(grid.map(|&c| c == '#').get(0, 0), Some(&true));
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+}
+
+#[test]
+fn test_empty_grid() {
+    let grid = Grid::parse("").unwrap();
+    assert!(grid.is_empty());
+    assert_eq!(grid.get_wrapping(0, 0), None);
+    assert_eq!(grid.rows().count(), 0);
+    assert_eq!(grid.to_string(), "");
+}
@@ -0,0 +1,232 @@
+//! Readers for the different shapes of puzzle input.
+//!
+//! All of these accept both `\n` and `\r\n` line endings, and a trailing newline at the end of the
+//! input does not produce an extra empty line. Line numbers are 1-based.
+
+use crate::error::{ParseError, Position};
+use crate::Result;
+use std::io::{BufRead, Read};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Reads the entirety of a source into a string.
+pub fn read_all(source: &mut dyn Read) -> Result<String> {
+    let mut buffer = String::new();
+    source.read_to_string(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Splits text into lines, keeping empty lines.
+///
+/// Trailing whitespace (including a `\r` from CRLF line endings) is removed from each line.
+pub fn lines(text: &str) -> Vec<&str> {
+    text.lines().map(str::trim_end).collect()
+}
+
+/// Splits text into non-empty lines, paired with their line number.
+pub fn numbered_lines(text: &str) -> Vec<(usize, &str)> {
+    lines(text)
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !line.is_empty())
+        .collect()
+}
+
+/// Splits text into non-empty lines.
+pub fn non_empty_lines(text: &str) -> Vec<&str> {
+    numbered_lines(text)
+        .into_iter()
+        .map(|(_, line)| line)
+        .collect()
+}
+
+/// A group of consecutive non-empty lines, separated from other records by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    /// The line number of the first line in this record.
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl Record<'_> {
+    /// Joins all lines of this record with a single space.
+    pub fn joined(&self) -> String {
+        self.lines.join(" ")
+    }
+}
+
+/// Splits text into records that are separated by one or more blank lines.
+pub fn records(text: &str) -> Vec<Record<'_>> {
+    let mut records = Vec::new();
+    let mut current: Option<Record> = None;
+    for (i, line) in lines(text).into_iter().enumerate() {
+        if line.trim().is_empty() {
+            records.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Record {
+                    line: i + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    records.extend(current);
+    records
+}
+
+/// Parses every non-empty line as a value, e.g. a list of integers.
+///
+/// Surrounding whitespace is ignored.
+pub fn parse_lines<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    numbered_lines(text)
+        .into_iter()
+        .map(|(number, line)| {
+            let trimmed = line.trim_start();
+            parse_field(number, line, line.len() - trimmed.len(), trimmed.trim_end())
+        })
+        .collect()
+}
+
+/// Parses every non-empty line of a reader as a value, reading one line at a time.
+///
+/// This is the incremental form of `parse_lines`, for input that is too large to hold in memory.
+/// Each item is the line number and the parsed value.
+pub fn parse_reader<T, R>(reader: R) -> ParsedLines<R, T>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    ParsedLines {
+        reader,
+        buffer: String::new(),
+        number: 0,
+        value: PhantomData,
+    }
+}
+
+/// An iterator over the values parsed from a reader, returned by `parse_reader`.
+pub struct ParsedLines<R, T> {
+    reader: R,
+    buffer: String,
+    /// The number of the last line that was read.
+    number: usize,
+    value: PhantomData<T>,
+}
+
+impl<R, T> Iterator for ParsedLines<R, T>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    type Item = Result<(usize, T)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => self.number += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+            let line = self.buffer.trim_end();
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let value = parse_field(self.number, line, line.len() - trimmed.len(), trimmed)
+                .map_err(Into::into);
+            return Some(value.map(|value| (self.number, value)));
+        }
+    }
+}
+
+/// Parses a field that starts at the given byte offset of a line.
+///
+/// If the field cannot be parsed, the error points at the field's position in the line.
+pub fn parse_field<T>(number: usize, line: &str, offset: usize, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    field
+        .parse()
+        .map_err(|e| ParseError::number(Position::in_line(number, line, offset), field, e))
+}
+
+/// Parses a rectangular grid of characters from the non-empty lines.
+pub fn char_grid(text: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+    for (number, line) in numbered_lines(text) {
+        let row: Vec<char> = line.chars().collect();
+        if let Some(first) = grid.first() {
+            if first.len() != row.len() {
+                return Err(ParseError::syntax(
+                    Position::new(number, 1),
+                    line,
+                    format!("a row of width {}", first.len()),
+                ));
+            }
+        }
+        grid.push(row);
+    }
+    Ok(grid)
+}
+
+#[test]
+fn test_lines() {
+    assert_eq!(lines("a\nb\n\nc\n"), vec!["a", "b", "", "c"]);
+    assert_eq!(lines("a\r\nb\r\n\r\nc"), vec!["a", "b", "", "c"]);
+    assert_eq!(lines(""), Vec::<&str>::new());
+}
+
+#[test]
+fn test_numbered_lines() {
+    assert_eq!(numbered_lines("a\n\nb\r\n"), vec![(1, "a"), (3, "b")]);
+    assert_eq!(non_empty_lines("\n\na\nb\n\n"), vec!["a", "b"]);
+}
+
+#[test]
+fn test_records() {
+    let recs = records("a b\nc\n\nd\r\n\r\n\r\ne\nf\n");
+    assert_eq!(recs.len(), 3);
+    assert_eq!(recs[0], Record { line: 1, lines: vec!["a b", "c"] });
+    assert_eq!(recs[1], Record { line: 4, lines: vec!["d"] });
+    assert_eq!(recs[2], Record { line: 7, lines: vec!["e", "f"] });
+    assert_eq!(recs[0].joined(), "a b c");
+    assert!(records("\n\n").is_empty());
+}
+
+#[test]
+fn test_parse_lines() {
+    assert_eq!(parse_lines::<i64>("1\n-2\n\n 3 \n").unwrap(), vec![1, -2, 3]);
+    let err = parse_lines::<usize>("1\r\n2\r\n  three\r\n").unwrap_err();
+    assert_eq!(err.position(), Position::new(3, 3));
+    assert_eq!(err.text(), "three");
+}
+
+#[test]
+fn test_parse_reader() {
+    let nums: Vec<(usize, i64)> = parse_reader("1\r\n\n -2 \n3".as_bytes()).collect::<Result<_>>().unwrap();
+    assert_eq!(nums, vec![(1, 1), (3, -2), (4, 3)]);
+    let mut parsed = parse_reader::<usize, _>("1\n  three\n4\n".as_bytes());
+    assert_eq!(parsed.next().unwrap().unwrap(), (1, 1));
+    let err = parsed.next().unwrap().unwrap_err();
+    assert_eq!(err.to_string(), r#"line 2, column 3: invalid number "three" (invalid digit found in string)"#);
+}
+
+#[test]
+fn test_char_grid() {
+    let grid = char_grid("#.\r\n.#\r\n").unwrap();
+    assert_eq!(grid, vec![vec!['#', '.'], vec!['.', '#']]);
+    let err = char_grid("#.\n.\n").unwrap_err();
+    assert_eq!(err.position().line, 2);
+}
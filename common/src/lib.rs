@@ -0,0 +1,16 @@
+//! Code shared between all of the days.
+
+pub mod answer;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod solution;
+
+pub use answer::{Answer, Value};
+pub use error::ParseError;
+pub use grid::Grid;
+pub use solution::{Part, Solution};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,155 @@
+//! Rendering of solution results for the different front ends.
+
+use crate::{Answer, Error, Part, Result, Solution, Value};
+use serde_json::{json, Map};
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// How results are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable text.
+    Text,
+    /// A JSON document.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format {:?} (expected text or json)", s).into()),
+        }
+    }
+}
+
+/// The result of running a single part of a solution.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl PartReport {
+    /// Runs a part of a solution, timing how long it takes.
+    pub fn run(solution: &dyn Solution, part: Part, text: &str) -> Result<Self> {
+        let start = Instant::now();
+        let answer = solution.run(part, text)?;
+        Ok(PartReport {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    /// Runs part 1 of a solution on input that is read incrementally, timing how long it takes.
+    pub fn run_streaming(solution: &dyn Solution, reader: &mut dyn BufRead) -> Result<Self> {
+        let start = Instant::now();
+        let answer = solution.part1_streaming(reader)?;
+        Ok(PartReport {
+            part: Part::One,
+            answer,
+            elapsed: start.elapsed(),
+        })
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let details: Map<_, _> = self.answer
+            .details
+            .iter()
+            .map(|(key, value)| (key.clone(), value.to_json()))
+            .collect();
+        json!({
+            "part": self.part.number(),
+            "answer": self.answer.value.to_json(),
+            "details": details,
+            "elapsed_ms": self.elapsed.as_secs_f64() * 1000.0,
+        })
+    }
+}
+
+impl Display for PartReport {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        writeln!(fmt, "Part {}", self.part)?;
+        writeln!(fmt, "======")?;
+        write!(fmt, "{}", self.answer)
+    }
+}
+
+/// The results of running the parts of one day's solution.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u32,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "day": self.day,
+            "parts": self.parts.iter().map(PartReport::to_json).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Where to print things that aren't part of the answers, like pictures. This is standard output
+/// for text, and standard error for JSON so that the output stays a single JSON document.
+pub fn console(format: Format) -> Box<dyn Write> {
+    match format {
+        Format::Text => Box::new(io::stdout()),
+        Format::Json => Box::new(io::stderr()),
+    }
+}
+
+/// Quotes a field of a CSV file if it needs to be, i.e. if it has a comma, quote or newline in it,
+/// or whitespace around it.
+pub fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) || field.trim() != field {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Value {
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Value::Int(i) => json!(i),
+            Value::Text(s) => json!(s),
+            Value::List(items) => items.iter().map(Value::to_json).collect(),
+            Value::Map(entries) => {
+                let entries: Map<_, _> = entries.iter().map(|(key, value)| (key.clone(), value.to_json())).collect();
+                entries.into()
+            }
+        }
+    }
+}
+
+#[test]
+fn test_to_json() {
+    let report = DayReport {
+        day: 1,
+        parts: vec![PartReport {
+            part: Part::Two,
+            answer: Answer::new(241861950).with("operands", vec![979, 366, 675]),
+            elapsed: Duration::from_micros(1500),
+        }],
+    };
+    assert_eq!(
+        report.to_json().to_string(),
+        r#"{"day":1,"parts":[{"part":2,"answer":241861950,"details":{"operands":[979,366,675]},"elapsed_ms":1.5}]}"#
+    );
+}
+
+#[test]
+fn test_csv_field() {
+    assert_eq!(csv_field("count"), "count");
+    assert_eq!(csv_field("count,forbid:\"x\""), "\"count,forbid:\"\"x\"\"\"");
+    assert_eq!(csv_field(" a"), "\" a\"");
+}
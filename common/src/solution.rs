@@ -0,0 +1,165 @@
+use crate::output::{self, DayReport, Format, PartReport};
+use crate::{input, Answer, Error, Result};
+use std::fmt::{self, Display, Formatter};
+use std::io::{stdin, BufRead, Write};
+use std::str::FromStr;
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part {:?} (expected 1 or 2)", s).into()),
+        }
+    }
+}
+
+/// A solution to a single day's puzzle.
+///
+/// Each part is given the full text of the puzzle input and is responsible for parsing it.
+pub trait Solution {
+    /// The day of the puzzle that this solves.
+    fn day(&self) -> u32;
+
+    fn part1(&self, input: &str) -> Result<Answer>;
+
+    fn part2(&self, input: &str) -> Result<Answer>;
+
+    /// Sets a day-specific option, such as the size of a window or a target value.
+    ///
+    /// By default, a solution has no options.
+    fn configure(&mut self, key: &str, _value: &str) -> Result<()> {
+        Err(format!("day {} has no option {:?}", self.day(), key).into())
+    }
+
+    /// Writes any pictures or reports that options asked for from the puzzle input. Anything that
+    /// isn't written to a file is printed to `console`.
+    ///
+    /// This is run once after the parts rather than inside them, so that writing is not timed by
+    /// benchmarks and does not depend on which parts are run. By default, nothing is written.
+    fn render(&self, _input: &str, _console: &mut dyn Write) -> Result<()> {
+        Ok(())
+    }
+
+    /// Runs part 1 on input that is read incrementally, for input too large to hold in memory.
+    ///
+    /// By default, this reads all of the input and calls `part1`.
+    fn part1_streaming(&self, reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        self.part1(&text)
+    }
+
+    /// Runs the given part of this solution.
+    fn run(&self, part: Part, input: &str) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+/// Sets an option on a solution from a `key=value` string.
+pub fn configure(solution: &mut dyn Solution, option: &str) -> Result<()> {
+    let mut split = option.splitn(2, '=');
+    match (split.next(), split.next()) {
+        (Some(key), Some(value)) => solution.configure(key.trim(), value.trim()),
+        _ => Err(format!("invalid option {:?} (expected key=value)", option).into()),
+    }
+}
+
+/// Runs both parts of a solution against standard input.
+///
+/// This is what each day's own binary does. It accepts `--format text|json` and any number of
+/// `--opt key=value` arguments. With `--stream`, only part 1 is run and the input is read
+/// incrementally using `Solution::part1_streaming`, and nothing is rendered. Errors are printed to
+/// standard error before exiting.
+pub fn main(solution: &mut dyn Solution) {
+    if let Err(e) = run_main(solution) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run_main(solution: &mut dyn Solution) -> Result<()> {
+    let mut format = Format::Text;
+    let mut stream = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stream" => stream = true,
+            "--format" | "--opt" => {
+                let value = args.next().ok_or_else(|| format!("{} requires a value", arg))?;
+                if arg == "--format" {
+                    format = value.parse()?;
+                } else {
+                    configure(solution, &value)?;
+                }
+            }
+            _ => return Err("usage: [--format text|json] [--opt key=value]... [--stream]".into()),
+        }
+    }
+
+    let mut report = DayReport {
+        day: solution.day(),
+        parts: Vec::new(),
+    };
+    let mut text = None;
+    if stream {
+        report.parts.push(PartReport::run_streaming(&*solution, &mut stdin().lock())?);
+    } else {
+        let input = {
+            let mut file = stdin();
+            input::read_all(&mut file)?
+        };
+        for part in Part::ALL.iter().copied() {
+            report.parts.push(PartReport::run(&*solution, part, &input)?);
+        }
+        text = Some(input);
+    }
+
+    if format == Format::Text {
+        for (i, part) in report.parts.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            println!("{}", part);
+        }
+    }
+
+    if format == Format::Json {
+        println!("{}", report.to_json());
+    }
+
+    if let Some(text) = &text {
+        solution.render(text, &mut output::console(format))?;
+    }
+
+    Ok(())
+}
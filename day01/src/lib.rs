@@ -0,0 +1,435 @@
+use aoc_common::{input, Answer, Result, Solution};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// The number that the expense report entries need to sum to.
+const TARGET: i64 = 2020;
+
+/// Report Repair.
+///
+/// Options:
+/// * `target` - the number that entries must sum to (default 2020).
+/// * `k` - how many entries must sum to the target (default 2 for part 1, 3 for part 2).
+/// * `solutions` - `first` to stop at the first solution, or `all` to find every one.
+/// * `distinct` - `value` to treat solutions with the same numbers as the same, or `index` to
+///   treat solutions using different entries as different even if the numbers are the same.
+pub struct Day01 {
+    pub target: i64,
+    pub k: Option<usize>,
+    pub search: Search,
+    pub distinct: Distinct,
+}
+
+impl Default for Day01 {
+    fn default() -> Self {
+        Day01 {
+            target: TARGET,
+            k: None,
+            search: Search::First,
+            distinct: Distinct::Value,
+        }
+    }
+}
+
+impl Solution for Day01 {
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        self.solve(&parse(text)?, self.k.unwrap_or(2))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        self.solve(&parse(text)?, self.k.unwrap_or(3))
+    }
+
+    fn part1_streaming(&self, reader: &mut dyn BufRead) -> Result<Answer> {
+        self.solve(&read_numbers(reader)?, self.k.unwrap_or(2))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "target" => self.target = value.parse()?,
+            "k" => self.k = Some(value.parse()?),
+            "solutions" => {
+                self.search = match value {
+                    "first" => Search::First,
+                    "all" => Search::All,
+                    _ => return Err(format!("invalid solutions {:?} (expected first or all)", value).into()),
+                }
+            }
+            "distinct" => {
+                self.distinct = match value {
+                    "value" => Distinct::Value,
+                    "index" => Distinct::Index,
+                    _ => return Err(format!("invalid distinct {:?} (expected value or index)", value).into()),
+                }
+            }
+            _ => return Err(format!("day 1 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+impl Day01 {
+    fn solve(&self, nums: &[i64], k: usize) -> Result<Answer> {
+        let (solutions, indices) = match self.distinct {
+            Distinct::Value => (find_k_sum(nums, k, self.target, self.search), None),
+            Distinct::Index => {
+                let indices = find_k_sum_indices(nums, k, self.target, self.search);
+                let solutions = indices.iter()
+                    .map(|combo| combo.iter().map(|&i| nums[i]).collect())
+                    .collect();
+                (solutions, Some(indices))
+            }
+        };
+        let first: &Vec<i64> = solutions.first()
+            .ok_or_else(|| format!("Could not find {} entries that sum to {}.", k, self.target))?;
+        let product = first.iter()
+            .try_fold(1i64, |acc, &num| acc.checked_mul(num))
+            .ok_or("The product of the entries is too large.")?;
+
+        let mut answer = Answer::new(product).with("operands", first.clone());
+        if let Some(indices) = &indices {
+            answer = answer.with("indices", indices[0].clone());
+        }
+        if self.search == Search::All {
+            answer = answer.with("count", solutions.len()).with("solutions", solutions);
+            if let Some(indices) = indices {
+                answer = answer.with("solution_indices", indices);
+            }
+        }
+        Ok(answer)
+    }
+}
+
+fn parse(text: &str) -> Result<Vec<i64>> {
+    Ok(input::parse_lines(text)?)
+}
+
+/// Reads the expense report one line at a time, without holding the text of the input in memory.
+pub fn read_numbers(reader: impl BufRead) -> Result<Vec<i64>> {
+    input::parse_reader(reader)
+        .map(|entry| entry.map(|(_, num)| num))
+        .collect()
+}
+
+/// Whether to look for only the first solution, or for all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Search {
+    First,
+    All,
+}
+
+/// What makes two solutions different from each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distinct {
+    /// Solutions are different if they use different numbers.
+    Value,
+    /// Solutions are different if they use different entries, even if the numbers are the same.
+    Index,
+}
+
+/// Finds `k` numbers that sum to `target`, each taken from a different entry of `nums`.
+///
+/// The numbers in each solution are in ascending order, and no two solutions have the same
+/// numbers. With `Search::First`, at most one solution is returned.
+///
+/// For k = 2 this is O(n) using a hash set, and for larger k it is O(n^(k-1)) after sorting.
+pub fn find_k_sum(nums: &[i64], k: usize, target: i64, search: Search) -> Vec<Vec<i64>> {
+    let mut solutions = Vec::new();
+    if k == 2 {
+        two_sum_hashed(nums, target, search, &mut solutions);
+    } else {
+        let mut sorted = nums.to_vec();
+        sorted.sort_unstable();
+        k_sum_sorted(&sorted, k, target, search, &mut Vec::new(), &mut solutions);
+    }
+    solutions
+}
+
+/// Finds pairs that sum to the target in a single pass, remembering the numbers seen so far.
+fn two_sum_hashed(nums: &[i64], target: i64, search: Search, solutions: &mut Vec<Vec<i64>>) {
+    let mut seen = HashSet::new();
+    let mut found = HashSet::new();
+    for &num in nums {
+        if let Some(other) = target.checked_sub(num) {
+            let pair = (other.min(num), other.max(num));
+            if seen.contains(&other) && found.insert(pair) {
+                solutions.push(vec![pair.0, pair.1]);
+                if search == Search::First {
+                    return;
+                }
+            }
+        }
+        seen.insert(num);
+    }
+}
+
+/// Finds k numbers in a sorted slice that sum to the target.
+///
+/// Each number is fixed in turn and the rest of the slice is searched for k - 1 numbers, down to
+/// two numbers which are found by walking in from both ends. Equal numbers are skipped so that
+/// the same solution is never reported twice.
+fn k_sum_sorted(
+    sorted: &[i64],
+    k: usize,
+    target: i64,
+    search: Search,
+    prefix: &mut Vec<i64>,
+    solutions: &mut Vec<Vec<i64>>,
+) {
+    let done = |solutions: &Vec<Vec<i64>>| search == Search::First && !solutions.is_empty();
+    match k {
+        0 => {
+            if target == 0 {
+                solutions.push(prefix.clone());
+            }
+        }
+        1 => {
+            if sorted.binary_search(&target).is_ok() {
+                prefix.push(target);
+                solutions.push(prefix.clone());
+                prefix.pop();
+            }
+        }
+        2 => {
+            let (mut lo, mut hi) = (0, sorted.len());
+            while lo + 1 < hi {
+                let (a, b) = (sorted[lo], sorted[hi - 1]);
+                match a.checked_add(b).map(|sum| sum.cmp(&target)) {
+                    Some(Ordering::Less) => lo += 1,
+                    Some(Ordering::Greater) | None => hi -= 1,
+                    Some(Ordering::Equal) => {
+                        prefix.extend(&[a, b]);
+                        solutions.push(prefix.clone());
+                        prefix.truncate(prefix.len() - 2);
+                        if done(solutions) {
+                            return;
+                        }
+                        while lo < hi && sorted[lo] == a {
+                            lo += 1;
+                        }
+                        while hi > lo && sorted[hi - 1] == b {
+                            hi -= 1;
+                        }
+                    }
+                }
+            }
+        }
+        _ => {
+            for i in 0..sorted.len() {
+                if i > 0 && sorted[i] == sorted[i - 1] {
+                    continue;
+                }
+                // every number after this one is at least as large, so the smallest sum that
+                // can be made from here on is k of this number
+                if let Some(min_sum) = sorted[i].checked_mul(k as i64) {
+                    if min_sum > target {
+                        break;
+                    }
+                }
+                let rest = match target.checked_sub(sorted[i]) {
+                    Some(rest) => rest,
+                    None => continue,
+                };
+                prefix.push(sorted[i]);
+                k_sum_sorted(&sorted[i + 1..], k - 1, rest, search, prefix, solutions);
+                prefix.pop();
+                if done(solutions) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Finds every combination of `k` entries of `nums` that sums to `target`.
+///
+/// Each solution is the (0-based) indices of its entries, in ascending order, and solutions are
+/// sorted. Unlike `find_k_sum`, entries that have the same number are treated as different, so
+/// `[1, 1, 2]` has two pairs that sum to 3. With `Search::First`, at most one solution is
+/// returned.
+pub fn find_k_sum_indices(nums: &[i64], k: usize, target: i64, search: Search) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..nums.len()).collect();
+    order.sort_by_key(|&i| nums[i]);
+    let mut solutions = Vec::new();
+    k_sum_indices(nums, &order, k, target, search, &mut Vec::new(), &mut solutions);
+    for solution in solutions.iter_mut() {
+        solution.sort_unstable();
+    }
+    solutions.sort();
+    solutions
+}
+
+/// Finds k entries that sum to the target, where `order` is the indices of the entries sorted by
+/// their number.
+///
+/// This works like `k_sum_sorted`, except that runs of equal numbers are expanded into every
+/// combination of their entries instead of being skipped.
+fn k_sum_indices(
+    nums: &[i64],
+    order: &[usize],
+    k: usize,
+    target: i64,
+    search: Search,
+    prefix: &mut Vec<usize>,
+    solutions: &mut Vec<Vec<usize>>,
+) {
+    let done = |solutions: &Vec<Vec<usize>>| search == Search::First && !solutions.is_empty();
+    let push = |combo: &[usize], solutions: &mut Vec<Vec<usize>>| {
+        let mut solution = prefix.clone();
+        solution.extend(combo);
+        solutions.push(solution);
+    };
+    match k {
+        0 => {
+            if target == 0 {
+                push(&[], solutions);
+            }
+        }
+        1 => {
+            let start = order.partition_point(|&i| nums[i] < target);
+            for &i in order[start..].iter().take_while(|&&i| nums[i] == target) {
+                push(&[i], solutions);
+                if done(solutions) {
+                    return;
+                }
+            }
+        }
+        2 => {
+            let (mut lo, mut hi) = (0, order.len());
+            while lo + 1 < hi {
+                let (a, b) = (nums[order[lo]], nums[order[hi - 1]]);
+                match a.checked_add(b).map(|sum| sum.cmp(&target)) {
+                    Some(Ordering::Less) => lo += 1,
+                    Some(Ordering::Greater) | None => hi -= 1,
+                    Some(Ordering::Equal) if a == b => {
+                        // everything between lo and hi is the same number, so any two of them work
+                        for x in lo..hi {
+                            for y in x + 1..hi {
+                                push(&[order[x], order[y]], solutions);
+                                if done(solutions) {
+                                    return;
+                                }
+                            }
+                        }
+                        return;
+                    }
+                    Some(Ordering::Equal) => {
+                        let lo_end = lo + order[lo..hi].iter().take_while(|&&i| nums[i] == a).count();
+                        let hi_start = hi - order[lo..hi].iter().rev().take_while(|&&i| nums[i] == b).count();
+                        for &x in order[lo..lo_end].iter() {
+                            for &y in order[hi_start..hi].iter() {
+                                push(&[x, y], solutions);
+                                if done(solutions) {
+                                    return;
+                                }
+                            }
+                        }
+                        lo = lo_end;
+                        hi = hi_start;
+                    }
+                }
+            }
+        }
+        _ => {
+            for p in 0..order.len() {
+                let num = nums[order[p]];
+                if let Some(min_sum) = num.checked_mul(k as i64) {
+                    if min_sum > target {
+                        break;
+                    }
+                }
+                let rest = match target.checked_sub(num) {
+                    Some(rest) => rest,
+                    None => continue,
+                };
+                prefix.push(order[p]);
+                k_sum_indices(nums, &order[p + 1..], k - 1, rest, search, prefix, solutions);
+                prefix.pop();
+                if done(solutions) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_find_k_sum() {
+    let nums = [1721, 979, 366, 299, 675, 1456];
+    assert_eq!(find_k_sum(&nums, 2, 2020, Search::First), vec![vec![299, 1721]]);
+    assert_eq!(find_k_sum(&nums, 3, 2020, Search::First), vec![vec![366, 675, 979]]);
+    assert_eq!(find_k_sum(&nums, 1, 979, Search::First), vec![vec![979]]);
+    assert!(find_k_sum(&nums, 4, 2020, Search::First).is_empty());
+}
+
+#[test]
+fn test_read_numbers() {
+    let text = include_str!("../example.txt");
+    assert_eq!(read_numbers(text.as_bytes()).unwrap(), parse(text).unwrap());
+    // blank lines are skipped, and errors point at the line they are on
+    assert_eq!(read_numbers("1721\n\n  979\n".as_bytes()).unwrap(), vec![1721, 979]);
+    let err = read_numbers("1721\n\n97x9\n".as_bytes()).unwrap_err();
+    assert!(err.to_string().starts_with("line 3, column 1:"), "{}", err);
+
+    let day = Day01::default();
+    assert_eq!(day.part1_streaming(&mut text.as_bytes()).unwrap(), day.part1(text).unwrap());
+}
+
+#[test]
+fn test_find_k_sum_all() {
+    let nums = [1, 2, 3, 4, 5, 5, -1];
+    assert_eq!(find_k_sum(&nums, 2, 6, Search::All), vec![vec![2, 4], vec![1, 5]]);
+    // a number can only be used twice if it appears twice
+    assert_eq!(find_k_sum(&nums, 2, 10, Search::All), vec![vec![5, 5]]);
+    assert!(find_k_sum(&[5], 2, 10, Search::All).is_empty());
+    assert_eq!(
+        find_k_sum(&nums, 3, 9, Search::All),
+        vec![vec![-1, 5, 5], vec![1, 3, 5], vec![2, 3, 4]]
+    );
+    assert_eq!(
+        find_k_sum(&nums, 4, 10, Search::All),
+        vec![vec![-1, 1, 5, 5], vec![-1, 2, 4, 5], vec![1, 2, 3, 4]]
+    );
+}
+
+#[test]
+fn test_find_k_sum_indices() {
+    let nums = [1, 1, 2, 2, 3];
+    assert_eq!(find_k_sum_indices(&nums, 2, 3, Search::All), vec![vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3]]);
+    assert_eq!(find_k_sum_indices(&nums, 2, 4, Search::All), vec![vec![0, 4], vec![1, 4], vec![2, 3]]);
+    assert_eq!(find_k_sum_indices(&nums, 2, 4, Search::First).len(), 1);
+    assert_eq!(find_k_sum_indices(&nums, 1, 2, Search::All), vec![vec![2], vec![3]]);
+    // by value, these are all the same two solutions
+    assert_eq!(find_k_sum(&nums, 2, 4, Search::All), vec![vec![2, 2], vec![1, 3]]);
+}
+
+#[test]
+fn test_find_k_sum_indices_brute_force() {
+    // compare against checking every combination of a pseudo-random list with lots of repeats
+    let mut seed = 12345u64;
+    let nums: Vec<i64> = (0..30)
+        .map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as i64 % 10 - 3
+        })
+        .collect();
+    for target in -5..20 {
+        let mut pairs = 0;
+        let mut triples = 0;
+        for i in 0..nums.len() {
+            for j in i + 1..nums.len() {
+                pairs += (nums[i] + nums[j] == target) as usize;
+                for k in j + 1..nums.len() {
+                    triples += (nums[i] + nums[j] + nums[k] == target) as usize;
+                }
+            }
+        }
+        assert_eq!(find_k_sum_indices(&nums, 2, target, Search::All).len(), pairs);
+        assert_eq!(find_k_sum_indices(&nums, 3, target, Search::All).len(), triples);
+    }
+}
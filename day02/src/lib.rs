@@ -0,0 +1,510 @@
+use aoc_common::error::{ParseError, Position};
+use aoc_common::output::csv_field;
+use aoc_common::{input, Answer, Result, Solution};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use regex::Regex;
+
+/// Password Philosophy.
+///
+/// Options:
+/// * `policy` - the policy to check every password against in both parts, instead of the count
+///   policy for part 1 and the position policy for part 2. See `parse_policy` for the syntax.
+/// * `report` - a path to write a CSV report to after running the parts, with whether each
+///   password passed each part's policy and why it failed.
+/// * `syntax` - `strict` to only accept lines exactly like `1-3 a: abcde` (the default), or
+///   `tolerant` to also accept extra whitespace, multi-character letters and `# comments`.
+#[derive(Default)]
+pub struct Day02 {
+    pub policy: Option<Box<dyn PasswordPolicy>>,
+    pub report: Option<String>,
+    pub syntax: Syntax,
+}
+
+impl Solution for Day02 {
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        Ok(count_valid(&check_each(&parse(text, self.syntax)?, self.policy_for(1))))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        Ok(count_valid(&check_each(&parse(text, self.syntax)?, self.policy_for(2))))
+    }
+
+    fn render(&self, text: &str, _console: &mut dyn Write) -> Result<()> {
+        if let Some(path) = &self.report {
+            let entries = parse(text, self.syntax)?;
+            let parts: Vec<_> = [1, 2]
+                .iter()
+                .map(|&part| (part, check_each(&entries, self.policy_for(part))))
+                .collect();
+            let mut out = BufWriter::new(File::create(path).map_err(|e| format!("{}: {}", path, e))?);
+            write_csv(&mut out, &parts)?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "policy" => self.policy = Some(parse_policy(value)?),
+            "report" => self.report = Some(value.to_string()),
+            "syntax" => {
+                self.syntax = match value {
+                    "strict" => Syntax::Strict,
+                    "tolerant" => Syntax::Tolerant,
+                    _ => return Err(format!("invalid syntax {:?} (expected strict or tolerant)", value).into()),
+                }
+            }
+            _ => return Err(format!("day 2 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+/// A line of the password database: the rule that was in effect and the password.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry<'a> {
+    /// The line number that this entry was on.
+    pub line: usize,
+    /// The first number of the rule, i.e. the minimum count or the first position.
+    pub lo: usize,
+    /// The second number of the rule, i.e. the maximum count or the second position.
+    pub hi: usize,
+    /// The letter that the rule is about. With tolerant syntax, this may be more than one
+    /// character, in which case the policies look for it as a substring.
+    pub letter: &'a str,
+    pub password: &'a str,
+}
+
+/// How strictly lines of the password database are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Syntax {
+    /// Only lines exactly like `1-3 a: abcde`.
+    #[default]
+    Strict,
+    /// Also allows extra whitespace around each part of the line, letters of more than one
+    /// character, and a comment starting with `#` after the password, e.g.
+    /// ` 1 - 3  ab :  abcde  # from the old system`.
+    Tolerant,
+}
+
+impl<'a> Entry<'a> {
+    /// Parses a line of the password database, e.g. `1-3 a: abcde`.
+    pub fn parse(number: usize, line: &'a str) -> Result<Self, ParseError> {
+        Entry::parse_with(number, line, Syntax::Strict)
+    }
+
+    /// Parses a line of the password database with the given syntax.
+    pub fn parse_with(number: usize, line: &'a str, syntax: Syntax) -> Result<Self, ParseError> {
+        lazy_static::lazy_static! {
+            static ref STRICT_RE: Regex = Regex::new(r"^(\d+)-(\d+) (\S): (\S+)$").unwrap();
+            static ref TOLERANT_RE: Regex =
+                Regex::new(r"^\s*(\d+)\s*-\s*(\d+)\s+([^\s:]+)\s*:\s*(\S+)\s*(?:#.*)?$").unwrap();
+        };
+        let (pat, expected) = match syntax {
+            Syntax::Strict => (&*STRICT_RE, "a line like `1-3 a: abcde`"),
+            Syntax::Tolerant => (&*TOLERANT_RE, "a line like `1-3 a: abcde  # comment`"),
+        };
+        let caps = pat.captures(line)
+            .ok_or_else(|| ParseError::syntax(Position::new(number, 1), line, expected))?;
+        // all groups are guaranteed to be present if the pattern matched
+        let lo = caps.get(1).unwrap();
+        let lo = input::parse_field(number, line, lo.start(), lo.as_str())?;
+        let hi = caps.get(2).unwrap();
+        let hi = input::parse_field(number, line, hi.start(), hi.as_str())?;
+        let letter = caps.get(3).unwrap().as_str();
+        let password = caps.get(4).unwrap().as_str();
+        Ok(Entry {
+            line: number,
+            lo,
+            hi,
+            letter,
+            password,
+        })
+    }
+}
+
+fn parse(text: &str, syntax: Syntax) -> Result<Vec<Entry<'_>>> {
+    let entries = input::numbered_lines(text)
+        .into_iter()
+        .map(|(number, line)| Entry::parse_with(number, line, syntax))
+        .collect::<Result<_, _>>()?;
+    Ok(entries)
+}
+
+/// A rule that a password must follow.
+pub trait PasswordPolicy {
+    /// A short description of the policy, e.g. "at least 8 characters".
+    fn name(&self) -> String;
+
+    /// Checks a password, returning why it breaks this policy if it does.
+    fn check(&self, entry: &Entry) -> Result<(), String>;
+
+    /// Problems with an entry that don't decide whether it passes, but may be mistakes in the
+    /// database.
+    fn warnings(&self, _entry: &Entry) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The policy from part 1: the letter must appear between `lo` and `hi` times.
+///
+/// If the letter is more than one character, occurrences of it are counted without overlapping.
+pub struct CountPolicy;
+
+impl PasswordPolicy for CountPolicy {
+    fn name(&self) -> String {
+        "count".to_string()
+    }
+
+    fn check(&self, entry: &Entry) -> Result<(), String> {
+        let count = entry.password.matches(entry.letter).count();
+        if count < entry.lo {
+            Err(format!("letter '{}' appears {} times, min {}", entry.letter, count, entry.lo))
+        } else if count > entry.hi {
+            Err(format!("letter '{}' appears {} times, max {}", entry.letter, count, entry.hi))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The policy from part 2: the letter must be at exactly one of positions `lo` and `hi`, counting
+/// from 1.
+///
+/// Positions count characters rather than bytes. A position of 0 or past the end of the password
+/// never matches, and is reported as a warning. If the letter is more than one character, it
+/// matches a position if it starts there.
+pub struct PositionPolicy;
+
+/// Checks whether a letter starts at a 1-based position of a password.
+fn starts_at(password: &str, position: usize, letter: &str) -> bool {
+    position.checked_sub(1)
+        .and_then(|i| password.char_indices().nth(i))
+        .is_some_and(|(offset, _)| password[offset..].starts_with(letter))
+}
+
+impl PasswordPolicy for PositionPolicy {
+    fn name(&self) -> String {
+        "position".to_string()
+    }
+
+    fn check(&self, entry: &Entry) -> Result<(), String> {
+        let first = starts_at(entry.password, entry.lo, entry.letter);
+        let second = starts_at(entry.password, entry.hi, entry.letter);
+        match (first, second) {
+            (true, false) | (false, true) => Ok(()),
+            (true, true) => Err(format!("both positions {} and {} are '{}'", entry.lo, entry.hi, entry.letter)),
+            (false, false) => Err(format!("neither position {} nor {} is '{}'", entry.lo, entry.hi, entry.letter)),
+        }
+    }
+
+    fn warnings(&self, entry: &Entry) -> Vec<String> {
+        let len = entry.password.chars().count();
+        [entry.lo, entry.hi].iter()
+            .filter(|&&position| position == 0 || position > len)
+            .map(|position| format!("position {} is outside of the {}-character password", position, len))
+            .collect()
+    }
+}
+
+/// The password must be at least this many characters long.
+pub struct MinLength(pub usize);
+
+impl PasswordPolicy for MinLength {
+    fn name(&self) -> String {
+        format!("min-length:{}", self.0)
+    }
+
+    fn check(&self, entry: &Entry) -> Result<(), String> {
+        let len = entry.password.chars().count();
+        if len >= self.0 {
+            Ok(())
+        } else {
+            Err(format!("{} characters long, not at least {}", len, self.0))
+        }
+    }
+}
+
+/// A kind of character that a password may be required to contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    /// Anything that is not a letter or a digit.
+    Symbol,
+}
+
+impl CharClass {
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            CharClass::Lower => c.is_lowercase(),
+            CharClass::Upper => c.is_uppercase(),
+            CharClass::Digit => c.is_numeric(),
+            CharClass::Symbol => !c.is_alphanumeric(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            CharClass::Lower => "lower",
+            CharClass::Upper => "upper",
+            CharClass::Digit => "digit",
+            CharClass::Symbol => "symbol",
+        }
+    }
+}
+
+/// The password must contain at least one character of each class.
+pub struct CharClasses(pub Vec<CharClass>);
+
+impl PasswordPolicy for CharClasses {
+    fn name(&self) -> String {
+        let names: Vec<_> = self.0.iter().map(|class| class.name()).collect();
+        format!("classes:{}", names.join("+"))
+    }
+
+    fn check(&self, entry: &Entry) -> Result<(), String> {
+        match self.0.iter().find(|class| !entry.password.chars().any(|c| class.contains(c))) {
+            Some(class) => Err(format!("no {} character", class.name())),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The password must not contain this text.
+pub struct Forbid(pub String);
+
+impl PasswordPolicy for Forbid {
+    fn name(&self) -> String {
+        format!("forbid:{}", self.0)
+    }
+
+    fn check(&self, entry: &Entry) -> Result<(), String> {
+        if entry.password.contains(self.0.as_str()) {
+            Err(format!("contains {:?}", self.0))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The password must follow every one of these policies.
+pub struct AllOf(pub Vec<Box<dyn PasswordPolicy>>);
+
+impl PasswordPolicy for AllOf {
+    fn name(&self) -> String {
+        let names: Vec<_> = self.0.iter().map(|policy| policy.name()).collect();
+        names.join(",")
+    }
+
+    fn check(&self, entry: &Entry) -> Result<(), String> {
+        self.0.iter().try_for_each(|policy| policy.check(entry))
+    }
+
+    fn warnings(&self, entry: &Entry) -> Vec<String> {
+        self.0.iter().flat_map(|policy| policy.warnings(entry)).collect()
+    }
+}
+
+/// Parses a comma-separated list of policies that must all be followed.
+///
+/// Each policy is one of:
+/// * `count` - the part 1 policy.
+/// * `position` - the part 2 policy.
+/// * `min-length:N` - at least N characters.
+/// * `classes:CLASS+CLASS...` - at least one character of each class, out of `lower`, `upper`,
+///   `digit` and `symbol`.
+/// * `forbid:TEXT` - must not contain TEXT.
+pub fn parse_policy(spec: &str) -> Result<Box<dyn PasswordPolicy>> {
+    let mut policies: Vec<Box<dyn PasswordPolicy>> = Vec::new();
+    for part in spec.split(',') {
+        let mut split = part.splitn(2, ':');
+        let policy: Box<dyn PasswordPolicy> = match (split.next().unwrap().trim(), split.next()) {
+            ("count", None) => Box::new(CountPolicy),
+            ("position", None) => Box::new(PositionPolicy),
+            ("min-length", Some(len)) => Box::new(MinLength(len.parse()?)),
+            ("classes", Some(classes)) => {
+                let classes = classes.split('+')
+                    .map(|class| match class {
+                        "lower" => Ok(CharClass::Lower),
+                        "upper" => Ok(CharClass::Upper),
+                        "digit" => Ok(CharClass::Digit),
+                        "symbol" => Ok(CharClass::Symbol),
+                        _ => Err(format!("unknown character class {:?}", class)),
+                    })
+                    .collect::<Result<_, _>>()?;
+                Box::new(CharClasses(classes))
+            }
+            ("forbid", Some(text)) if !text.is_empty() => Box::new(Forbid(text.to_string())),
+            _ => return Err(format!("invalid password policy {:?}", part).into()),
+        };
+        policies.push(policy);
+    }
+    if policies.len() == 1 {
+        Ok(policies.pop().unwrap())
+    } else {
+        Ok(Box::new(AllOf(policies)))
+    }
+}
+
+impl Day02 {
+    /// The policy that passwords are checked against in a part.
+    fn policy_for(&self, part: u32) -> &dyn PasswordPolicy {
+        match (&self.policy, part) {
+            (Some(policy), _) => policy.as_ref(),
+            (None, 1) => &CountPolicy,
+            (None, _) => &PositionPolicy,
+        }
+    }
+}
+
+/// Counts how many passwords passed their policy.
+fn count_valid(verdicts: &[Verdict]) -> Answer {
+    let valid = verdicts.iter().filter(|verdict| verdict.result.is_ok()).count();
+    let warnings: Vec<_> = verdicts.iter()
+        .flat_map(|verdict| verdict.warnings.iter().map(move |warning| format!("line {}: {}", verdict.line, warning)))
+        .collect();
+    let mut answer = Answer::new(valid).with("invalid", verdicts.len() - valid);
+    if !warnings.is_empty() {
+        answer = answer.with("warnings", warnings);
+    }
+    answer
+}
+
+/// Whether a single password follows a policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict<'a> {
+    pub line: usize,
+    /// The name of the policy that the password was checked against.
+    pub policy: String,
+    pub password: &'a str,
+    /// `Ok` if the password passed, or why it failed.
+    pub result: Result<(), String>,
+    pub warnings: Vec<String>,
+}
+
+/// Checks every password against a policy.
+pub fn check_each<'a>(entries: &[Entry<'a>], policy: &dyn PasswordPolicy) -> Vec<Verdict<'a>> {
+    let name = policy.name();
+    entries.iter()
+        .map(|entry| Verdict {
+            line: entry.line,
+            policy: name.clone(),
+            password: entry.password,
+            result: policy.check(entry),
+            warnings: policy.warnings(entry),
+        })
+        .collect()
+}
+
+/// Writes the verdicts of each part as CSV, with a header row of
+/// `part,line,policy,password,result,reason,warnings`.
+///
+/// Multiple warnings are separated by `; `.
+pub fn write_csv(out: &mut dyn Write, parts: &[(u32, Vec<Verdict>)]) -> io::Result<()> {
+    writeln!(out, "part,line,policy,password,result,reason,warnings")?;
+    for (part, verdicts) in parts {
+        for verdict in verdicts {
+            let (result, reason) = match &verdict.result {
+                Ok(()) => ("pass", ""),
+                Err(reason) => ("fail", reason.as_str()),
+            };
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{}",
+                part,
+                verdict.line,
+                csv_field(&verdict.policy),
+                csv_field(verdict.password),
+                result,
+                csv_field(reason),
+                csv_field(&verdict.warnings.join("; "))
+            )?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_policies() {
+    let entry = Entry::parse(1, "1-3 a: abcde").unwrap();
+    assert_eq!(entry, Entry { line: 1, lo: 1, hi: 3, letter: "a", password: "abcde" });
+    assert!(CountPolicy.check(&entry).is_ok());
+    assert!(PositionPolicy.check(&entry).is_ok());
+    assert_eq!(MinLength(6).check(&entry), Err("5 characters long, not at least 6".to_string()));
+
+    let policy = parse_policy("count,classes:lower+digit").unwrap();
+    assert_eq!(policy.name(), "count,classes:lower+digit");
+    assert_eq!(policy.check(&entry), Err("no digit character".to_string()));
+    let policy = parse_policy("position, forbid:cd").unwrap();
+    assert_eq!(policy.check(&entry), Err("contains \"cd\"".to_string()));
+    assert!(parse_policy("min-length").is_err());
+    assert!(parse_policy("classes:lower+weird").is_err());
+}
+
+#[test]
+fn test_write_csv() {
+    let entries = parse(include_str!("../example.txt"), Syntax::Strict).unwrap();
+    let mut out = Vec::new();
+    let parts = [(1, check_each(&entries[..1], &CountPolicy)), (2, check_each(&entries, &PositionPolicy))];
+    write_csv(&mut out, &parts).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "part,line,policy,password,result,reason,warnings\n\
+         1,1,count,abcde,pass,,\n\
+         2,1,position,abcde,pass,,\n\
+         2,2,position,cdefg,fail,neither position 1 nor 3 is 'b',\n\
+         2,3,position,ccccccccc,fail,both positions 2 and 9 are 'c',\n"
+    );
+}
+
+#[test]
+fn test_position_out_of_range() {
+    let entry = Entry::parse(1, "0-2 a: aba").unwrap();
+    assert_eq!(PositionPolicy.check(&entry), Err("neither position 0 nor 2 is 'a'".to_string()));
+    assert_eq!(PositionPolicy.warnings(&entry), vec!["position 0 is outside of the 3-character password"]);
+    let entry = Entry::parse(2, "1-4 a: aba").unwrap();
+    assert!(PositionPolicy.check(&entry).is_ok());
+    assert_eq!(PositionPolicy.warnings(&entry), vec!["position 4 is outside of the 3-character password"]);
+
+    let answer = Day02::default().part2("0-2 a: aba\n1-2 a: ab\n").unwrap();
+    assert_eq!(answer.value, 1.into());
+    assert_eq!(
+        answer.detail("warnings"),
+        Some(&vec!["line 1: position 0 is outside of the 3-character password"].into())
+    );
+}
+
+#[test]
+fn test_position_multi_byte() {
+    // positions count characters, so 'ü' is at position 2 even though it takes two bytes
+    let entry = Entry::parse(1, "2-4 ü: aüc$-ü").unwrap();
+    assert_eq!(entry.password, "aüc$-ü");
+    assert!(PositionPolicy.check(&entry).is_ok());
+    assert!(PositionPolicy.warnings(&entry).is_empty());
+    let entry = Entry::parse(1, "1-3 #: #!#").unwrap();
+    assert_eq!(PositionPolicy.check(&entry), Err("both positions 1 and 3 are '#'".to_string()));
+    assert!(Entry::parse(1, "1-3 a: ab cd").is_err());
+}
+
+#[test]
+fn test_tolerant_syntax() {
+    let entry = Entry::parse_with(4, "  1 -  3  ab :  xabab  # from the old system", Syntax::Tolerant).unwrap();
+    assert_eq!(entry, Entry { line: 4, lo: 1, hi: 3, letter: "ab", password: "xabab" });
+    assert_eq!(CountPolicy.check(&entry), Ok(()));
+    assert_eq!(PositionPolicy.check(&entry), Err("neither position 1 nor 3 is 'ab'".to_string()));
+    assert!(Entry::parse(4, "  1 -  3  ab :  xabab  # from the old system").is_err());
+
+    // every strict line is also a tolerant one, and means the same
+    for line in include_str!("../example.txt").lines() {
+        assert_eq!(Entry::parse_with(1, line, Syntax::Tolerant), Entry::parse(1, line));
+    }
+    assert_eq!(Entry::parse_with(1, "1-3 a: a#b", Syntax::Tolerant).unwrap().password, "a#b");
+    assert!(Entry::parse_with(1, "1-3 a: # only a comment", Syntax::Tolerant).is_err());
+}
@@ -0,0 +1,304 @@
+pub mod render;
+
+use aoc_common::error::{ParseError, Position};
+use aoc_common::{input, Answer, Error, Grid, Result, Solution, Value};
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// The slopes that are checked in part 2 if no others are given.
+const SLOPES: &[Slope] = &[
+    Slope { right: 1, down: 1 },
+    Slope { right: 3, down: 1 },
+    Slope { right: 5, down: 1 },
+    Slope { right: 7, down: 1 },
+    Slope { right: 1, down: 2 },
+];
+
+/// Toboggan Trajectory.
+///
+/// Options:
+/// * `slopes` - a comma-separated list of slopes to check in part 2, like `3/1,-1/2`, where each
+///   slope is how far right and then down the toboggan moves at each step.
+/// * `slopes-file` - a file to read the slopes for part 2 from, with one slope per line.
+/// * `search` - `min` or `max` to have part 2 find the slope with the fewest or most tree hits
+///   instead, out of every slope in the `right` and `down` ranges.
+/// * `right` - the range of steps right to search, like `-3..=7` (default 1..=7).
+/// * `down` - the range of steps down to search, like `1..=2` (default 1..=2).
+/// * `render` - a path to draw the path of the toboggan to, after running the parts. Files ending
+///   in `.svg` or `.ppm` are written as images, and anything else as text. `-` prints the text
+///   picture after the answers instead, or to standard error with `--format json`.
+/// * `render-slope` - the slope to draw (default 3/1).
+pub struct Day03 {
+    pub slopes: Vec<Slope>,
+    pub search: Option<Goal>,
+    pub right: RangeInclusive<isize>,
+    pub down: RangeInclusive<usize>,
+    pub render: Option<String>,
+    pub render_slope: Slope,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Day03 {
+            slopes: SLOPES.to_vec(),
+            search: None,
+            right: 1..=7,
+            down: 1..=2,
+            render: None,
+            render_slope: Slope { right: 3, down: 1 },
+        }
+    }
+}
+
+impl Solution for Day03 {
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        part1(&Grid::parse(text)?)
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        let grid = Grid::parse(text)?;
+        match self.search {
+            Some(goal) => search(&grid, goal, self.right.clone(), self.down.clone()),
+            None => part2(&grid, &self.slopes),
+        }
+    }
+
+    fn render(&self, text: &str, console: &mut dyn Write) -> Result<()> {
+        match self.render.as_deref() {
+            Some("-") => writeln!(console, "{}", render::render(&Grid::parse(text)?, self.render_slope)?)?,
+            Some(path) => {
+                write_render(Path::new(path), &Grid::parse(text)?, self.render_slope)
+                    .map_err(|e| format!("{}: {}", path, e))?;
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "slopes" => {
+                self.slopes = value.split(',')
+                    .map(|slope| slope.trim().parse())
+                    .collect::<Result<_>>()?;
+            }
+            "slopes-file" => {
+                let text = input::read_all(&mut File::open(value).map_err(|e| format!("{}: {}", value, e))?)?;
+                self.slopes = parse_slopes(&text)?;
+            }
+            "search" => {
+                self.search = match value {
+                    "min" => Some(Goal::Min),
+                    "max" => Some(Goal::Max),
+                    _ => return Err(format!("invalid search {:?} (expected min or max)", value).into()),
+                }
+            }
+            "right" => self.right = parse_range(value)?,
+            "down" => {
+                let down = parse_range(value)?;
+                if *down.start() == 0 {
+                    return Err("the toboggan must move down at least 1 at each step".into());
+                }
+                self.down = down;
+            }
+            "render" => self.render = Some(value.to_string()),
+            "render-slope" => self.render_slope = value.parse()?,
+            _ => return Err(format!("day 3 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+/// How far the toboggan moves at each step. Moving right by a negative amount moves left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+impl FromStr for Slope {
+    type Err = Error;
+
+    /// Parses a slope like `3/1`, meaning 3 right and 1 down.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut split = s.splitn(2, '/');
+        let (right, down) = match (split.next(), split.next()) {
+            (Some(right), Some(down)) => (right.trim().parse()?, down.trim().parse()?),
+            _ => return Err(format!("invalid slope {:?} (expected right/down, like 3/1)", s).into()),
+        };
+        if down == 0 {
+            return Err(format!("invalid slope {:?} (the toboggan must move down at least 1)", s).into());
+        }
+        Ok(Slope { right, down })
+    }
+}
+
+impl Display for Slope {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        write!(fmt, "Right {}, down {}", self.right, self.down)
+    }
+}
+
+/// Parses one slope per non-empty line.
+fn parse_slopes(text: &str) -> Result<Vec<Slope>, ParseError> {
+    input::numbered_lines(text)
+        .into_iter()
+        .map(|(number, line)| {
+            line.trim()
+                .parse()
+                .map_err(|_| ParseError::syntax(Position::new(number, 1), line, "a slope like `3/1`"))
+        })
+        .collect()
+}
+
+/// Parses an inclusive range like `1..=7`, or a single number.
+fn parse_range<T>(s: &str) -> Result<RangeInclusive<T>>
+where
+    T: FromStr + PartialOrd + Copy,
+    T::Err: std::error::Error + 'static,
+{
+    let range = match s.find("..=") {
+        Some(i) => s[..i].trim().parse()?..=s[i + 3..].trim().parse()?,
+        None => {
+            let n = s.trim().parse()?;
+            n..=n
+        }
+    };
+    if range.is_empty() {
+        return Err(format!("invalid range {:?} (expected something like 1..=7)", s).into());
+    }
+    Ok(range)
+}
+
+/// Whether to search for the slope with the fewest or the most tree hits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Min,
+    Max,
+}
+
+/// Finds every square that the toboggan stops on, from the top left until it goes past the bottom
+/// of the map. The x coordinates are not wrapped around to the width of the map.
+fn trace(grid: &Grid<char>, slope: Slope) -> Result<Vec<(isize, usize)>> {
+    let mut y = 0;
+    let mut x = 0;
+    let mut path = Vec::new();
+    while grid.get_wrapping(x, y).is_some() {
+        path.push((x, y));
+        // going past the largest y is going past the bottom of the map
+        y = match y.checked_add(slope.down) {
+            Some(y) => y,
+            None => break,
+        };
+        x = x.checked_add(slope.right)
+            .ok_or_else(|| format!("The toboggan goes too far sideways on slope {}.", slope))?;
+    }
+    Ok(path)
+}
+
+fn count_hits(grid: &Grid<char>, slope: Slope) -> Result<usize> {
+    let path = trace(grid, slope)?;
+    Ok(path.into_iter().filter(|&(x, y)| grid.get_wrapping(x, y) == Some(&'#')).count())
+}
+
+/// Draws the path of the toboggan to a file, picking the format from its extension.
+fn write_render(path: &Path, grid: &Grid<char>, slope: Slope) -> Result<()> {
+    let picture = render::render(grid, slope)?;
+    let mut out = BufWriter::new(File::create(path)?);
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("svg") => render::write_svg(&mut out, &picture)?,
+        Some("ppm") => render::write_ppm(&mut out, &picture)?,
+        _ => writeln!(out, "{}", picture)?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn part1(grid: &Grid<char>) -> Result<Answer> {
+    Ok(Answer::new(count_hits(grid, Slope { right: 3, down: 1 })?))
+}
+
+fn part2(grid: &Grid<char>, slopes: &[Slope]) -> Result<Answer> {
+    let mut product: usize = 1;
+    let mut entries = Vec::new();
+    for &slope in slopes {
+        let hits = count_hits(grid, slope)?;
+        let slope = format!("{}/{}", slope.right, slope.down);
+        entries.push(Value::Map(vec![("slope".to_string(), slope.into()), ("hits".to_string(), hits.into())]));
+        product = product.checked_mul(hits).ok_or("The product of the tree hits is too large.")?;
+    }
+    Ok(Answer::new(product).with("slopes", entries))
+}
+
+/// Finds the slope in the given ranges with the fewest or most hits. If several slopes are tied,
+/// the one with the smallest steps right and then down is picked.
+fn search(grid: &Grid<char>, goal: Goal, right: RangeInclusive<isize>, down: RangeInclusive<usize>) -> Result<Answer> {
+    let slopes = right.flat_map(|right| down.clone().map(move |down| Slope { right, down }));
+    let mut best: Option<(Slope, usize)> = None;
+    for slope in slopes {
+        let hits = count_hits(grid, slope)?;
+        let better = match (best, goal) {
+            (None, _) => true,
+            (Some((_, best_hits)), Goal::Min) => hits < best_hits,
+            (Some((_, best_hits)), Goal::Max) => hits > best_hits,
+        };
+        if better {
+            best = Some((slope, hits));
+        }
+    }
+    let (slope, hits) = best.ok_or("There are no slopes to search.")?;
+    Ok(Answer::new(hits).with("slope", slope.to_string()))
+}
+
+#[test]
+fn test_slopes() {
+    let grid = Grid::parse(include_str!("../example.txt")).unwrap();
+    assert_eq!(count_hits(&grid, Slope { right: 3, down: 1 }).unwrap(), 7);
+    // moving left across a grid that wraps around is the same as moving right by the rest of it
+    let width = grid.width() as isize;
+    assert_eq!(
+        count_hits(&grid, Slope { right: -1, down: 1 }).unwrap(),
+        count_hits(&grid, Slope { right: width - 1, down: 1 }).unwrap()
+    );
+
+    // huge slopes and products are errors rather than overflowing
+    assert_eq!(count_hits(&grid, Slope { right: 1, down: usize::MAX }).unwrap(), 0);
+    assert!(count_hits(&grid, Slope { right: isize::MAX, down: 1 }).is_err());
+    assert!(part2(&grid, &[Slope { right: 1, down: 1 }; 70]).is_err());
+
+    assert_eq!("-2/3".parse::<Slope>().unwrap(), Slope { right: -2, down: 3 });
+    assert!("2/0".parse::<Slope>().is_err());
+    assert!("2".parse::<Slope>().is_err());
+    let err = parse_slopes("1/1\n\nleft\n").unwrap_err();
+    assert_eq!(err.position(), Position::new(3, 1));
+}
+
+#[test]
+fn test_search() {
+    let grid = Grid::parse(include_str!("../example.txt")).unwrap();
+    let answer = search(&grid, Goal::Max, 1..=7, 1..=1).unwrap();
+    assert_eq!(answer.value, 7.into());
+    assert_eq!(answer.detail("slope"), Some(&"Right 3, down 1".into()));
+    let answer = search(&grid, Goal::Min, 1..=7, 1..=1).unwrap();
+    assert_eq!(answer.value, 1.into());
+    assert_eq!(answer.detail("slope"), Some(&"Right 2, down 1".into()));
+    let answer = search(&grid, Goal::Min, 1..=7, 1..=2).unwrap();
+    assert_eq!(answer.value, 0.into());
+    assert_eq!(answer.detail("slope"), Some(&"Right 5, down 2".into()));
+    assert_eq!(parse_range::<isize>("-3..=7").unwrap(), -3..=7);
+    assert_eq!(parse_range::<usize>("2").unwrap(), 2..=2);
+    assert!(parse_range::<usize>("3..=1").is_err());
+
+    // a rejected range leaves the old one in place
+    let mut day = Day03::default();
+    assert!(day.configure("down", "0..=2").is_err());
+    assert_eq!(day.down, 1..=2);
+}
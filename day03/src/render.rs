@@ -0,0 +1,124 @@
+//! Drawing the path that the toboggan takes, like the pictures in the puzzle.
+
+use crate::{trace, Slope};
+use aoc_common::{Grid, Result};
+use std::io::{self, Write};
+
+/// The widest picture that will be drawn, in squares.
+const MAX_COLUMNS: usize = 1 << 16;
+
+/// How many pixels wide and tall each square is in a PPM image.
+const PPM_SCALE: usize = 4;
+
+/// Draws the path of the toboggan. The map is repeated to the left or right as many times as the
+/// path needs, and each square on the path is marked `X` if it is a tree or `O` if it is open, as
+/// in the puzzle.
+pub fn render(grid: &Grid<char>, slope: Slope) -> Result<Grid<char>> {
+    let path = trace(grid, slope)?;
+    let width = grid.width() as isize;
+    // the repeats of the map that the path passes through, counting the original one as 0
+    let first = path.iter().map(|&(x, _)| x.div_euclid(width)).min().unwrap_or(0);
+    let last = path.iter().map(|&(x, _)| x.div_euclid(width)).max().unwrap_or(0);
+    let left = first * width;
+    let columns = (last - first)
+        .checked_add(1)
+        .and_then(|repeats| repeats.checked_mul(width))
+        .filter(|&columns| columns as usize <= MAX_COLUMNS)
+        .ok_or_else(|| format!("The picture of slope {} would be too wide.", slope))? as usize;
+
+    let rows = (0..grid.height())
+        .map(|y| (0..columns).map(|x| *grid.get_wrapping(left + x as isize, y).unwrap()).collect())
+        .collect();
+    let mut picture = Grid::from_rows(rows).unwrap();
+    for (x, y) in path {
+        let cell = picture.get_mut((x - left) as usize, y).unwrap();
+        *cell = if *cell == '#' { 'X' } else { 'O' };
+    }
+    Ok(picture)
+}
+
+/// The colour that a square of a picture is drawn in.
+fn colour(cell: char) -> (u8, u8, u8) {
+    match cell {
+        '#' => (34, 139, 34),
+        'X' => (220, 20, 60),
+        'O' => (30, 144, 255),
+        _ => (255, 255, 255),
+    }
+}
+
+/// Writes a picture from `render` as an SVG image, with one square per cell.
+pub fn write_svg(out: &mut dyn Write, picture: &Grid<char>) -> io::Result<()> {
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+        picture.width(),
+        picture.height()
+    )?;
+    writeln!(out, r#"<rect width="100%" height="100%" fill="white"/>"#)?;
+    for (y, row) in picture.rows().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            if cell == '.' {
+                continue;
+            }
+            let (r, g, b) = colour(cell);
+            writeln!(out, r#"<rect x="{}" y="{}" width="1" height="1" fill="rgb({},{},{})"/>"#, x, y, r, g, b)?;
+        }
+    }
+    writeln!(out, "</svg>")
+}
+
+/// Writes a picture from `render` as a binary PPM image, with each cell drawn as a square of
+/// pixels.
+pub fn write_ppm(out: &mut dyn Write, picture: &Grid<char>) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", picture.width() * PPM_SCALE, picture.height() * PPM_SCALE)?;
+    for row in picture.rows() {
+        let line: Vec<u8> = row.iter()
+            .flat_map(|&cell| {
+                let (r, g, b) = colour(cell);
+                [r, g, b].repeat(PPM_SCALE)
+            })
+            .collect();
+        for _ in 0..PPM_SCALE {
+            out.write_all(&line)?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_render() {
+    let grid = Grid::parse(include_str!("../example.txt")).unwrap();
+    let picture = render(&grid, Slope { right: 3, down: 1 }).unwrap();
+    // the picture from the puzzle, cut down to the three copies of the map that the path needs
+    let expected = "\
+O.##.........##.........##.......
+#..O#...#..#...#...#..#...#...#..
+.#....X..#..#....#..#..#....#..#.
+..#.#...#O#..#.#...#.#..#.#...#.#
+.#...##..#..X...##..#..#...##..#.
+..#.##.......#.X#.......#.##.....
+.#.#.#....#.#.#.#.O..#.#.#.#....#
+.#........#.#........X.#........#
+#.##...#...#.##...#...#.X#...#...
+#...##....##...##....##...#X....#
+.#..#...#.#.#..#...#.#.#..#...X.#";
+    assert_eq!(picture.to_string(), expected);
+    assert_eq!(picture.to_string().matches('X').count(), 7);
+
+    // going left puts the repeats of the map on the left
+    let picture = render(&grid, Slope { right: -1, down: 2 }).unwrap();
+    assert_eq!(picture.width(), 2 * grid.width());
+    assert_eq!(picture.get(grid.width(), 0), Some(&'O'));
+    assert_eq!(picture.get(grid.width() - 1, 2), Some(&'O'));
+}
+
+#[test]
+fn test_write_ppm() {
+    let picture = Grid::parse("#X\n").unwrap();
+    let mut out = Vec::new();
+    write_ppm(&mut out, &picture).unwrap();
+    let header = format!("P6\n{} {}\n255\n", 2 * PPM_SCALE, PPM_SCALE);
+    assert!(out.starts_with(header.as_bytes()));
+    assert_eq!(out.len(), header.len() + 2 * PPM_SCALE * PPM_SCALE * 3);
+}
@@ -0,0 +1,143 @@
+//! Writing checked passports out in formats that other tools can load.
+
+use crate::schema::Schema;
+use crate::{Passport, PassportError};
+use aoc_common::output::csv_field;
+use serde_json::{json, Map};
+use std::io::{self, Write};
+
+/// A passport that has been checked against a schema, with its fields put in a standard order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked<'a> {
+    /// The line number that the passport starts on.
+    pub line: usize,
+    /// The fields in the order of the schema, followed by any fields that aren't in the schema in
+    /// the order they were written. Only the first value of a duplicated field is kept.
+    pub fields: Vec<(&'a str, &'a str)>,
+    /// Everything that makes the passport invalid, including duplicated fields.
+    pub errors: Vec<PassportError>,
+    /// Fields that were given more than once.
+    pub duplicates: Vec<&'a str>,
+    /// Fields that aren't in the schema. These don't make a passport invalid.
+    pub unknown: Vec<&'a str>,
+}
+
+impl<'a> Checked<'a> {
+    pub fn new(passport: &Passport<'a>, schema: &Schema) -> Self {
+        let mut fields: Vec<_> = schema.fields
+            .iter()
+            .filter_map(|rule| passport.fields.iter().find(|(key, _)| *key == rule.name).copied())
+            .collect();
+        let mut duplicates = Vec::new();
+        let mut unknown = Vec::new();
+        for (i, &(key, value)) in passport.fields.iter().enumerate() {
+            let earlier = passport.fields[..i].iter().filter(|(k, _)| *k == key).count();
+            if earlier == 1 {
+                duplicates.push(key);
+            }
+            if earlier == 0 && schema.get(key).is_none() {
+                unknown.push(key);
+                fields.push((key, value));
+            }
+        }
+        Checked {
+            line: passport.line,
+            fields,
+            errors: passport.validate(schema),
+            duplicates,
+            unknown,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|&(_, value)| value)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let fields: Map<_, _> = self.fields
+            .iter()
+            .map(|&(key, value)| (key.to_string(), json!(value)))
+            .collect();
+        json!({
+            "line": self.line,
+            "valid": self.is_valid(),
+            "fields": fields,
+            "errors": self.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "duplicates": self.duplicates,
+            "unknown": self.unknown,
+        })
+    }
+}
+
+/// Writes one JSON object per line for each passport.
+pub fn write_json_lines(out: &mut dyn Write, passports: &[Checked]) -> io::Result<()> {
+    for passport in passports {
+        writeln!(out, "{}", passport.to_json())?;
+    }
+    Ok(())
+}
+
+/// Writes passports as CSV, with a column for each field in the schema.
+///
+/// The columns are `line`, `valid`, each field in the schema, `unknown` (any other fields, as
+/// space-separated `key:value` pairs), `duplicates` and `errors` (separated by `; `).
+pub fn write_csv(out: &mut dyn Write, schema: &Schema, passports: &[Checked]) -> io::Result<()> {
+    let mut header = vec!["line", "valid"];
+    header.extend(schema.fields.iter().map(|rule| rule.name.as_str()));
+    header.extend(&["unknown", "duplicates", "errors"]);
+    let header: Vec<_> = header.into_iter().map(csv_field).collect();
+    writeln!(out, "{}", header.join(","))?;
+
+    for passport in passports {
+        let mut row = vec![passport.line.to_string(), passport.is_valid().to_string()];
+        row.extend(schema.fields.iter().map(|rule| passport.get(&rule.name).unwrap_or("").to_string()));
+        let unknown: Vec<_> = passport.unknown
+            .iter()
+            .map(|key| format!("{}:{}", key, passport.get(key).unwrap_or("")))
+            .collect();
+        row.push(unknown.join(" "));
+        row.push(passport.duplicates.join(" "));
+        let errors: Vec<_> = passport.errors.iter().map(ToString::to_string).collect();
+        row.push(errors.join("; "));
+        let row: Vec<_> = row.iter().map(|field| csv_field(field)).collect();
+        writeln!(out, "{}", row.join(","))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_export() {
+    let text = "hcl:#623a2f pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980 cid:1\n\n\
+                byr:1926 hair:long byr:1927\n";
+    let schema = Schema::parse("byr required year 1920..=2002\nhcl optional colour\n").unwrap();
+    let passports: Vec<_> = crate::parse(text)
+        .unwrap()
+        .iter()
+        .map(|passport| Checked::new(passport, &schema))
+        .collect();
+    assert_eq!(passports[0].fields[..2], [("byr", "1980"), ("hcl", "#623a2f")]);
+    assert_eq!(passports[0].unknown, vec!["pid", "hgt", "ecl", "iyr", "eyr", "cid"]);
+    assert!(passports[0].is_valid());
+    assert_eq!(passports[1].fields, vec![("byr", "1926"), ("hair", "long")]);
+    assert_eq!(passports[1].duplicates, vec!["byr"]);
+    assert!(!passports[1].is_valid());
+
+    let mut out = Vec::new();
+    write_json_lines(&mut out, &passports[1..]).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        r#"{"line":3,"valid":false,"fields":{"byr":"1926","hair":"long"},"errors":["byr is given more than once"],"duplicates":["byr"],"unknown":["hair"]}"#.to_string() + "\n"
+    );
+
+    let mut out = Vec::new();
+    write_csv(&mut out, &schema, &passports[1..]).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "line,valid,byr,hcl,unknown,duplicates,errors\n\
+         3,false,1926,,hair:long,byr,byr is given more than once\n"
+    );
+}
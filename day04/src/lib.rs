@@ -0,0 +1,202 @@
+pub mod export;
+pub mod schema;
+
+use aoc_common::error::{ParseError, Position};
+use aoc_common::input::{self, Record};
+use aoc_common::{Answer, Result, Solution};
+use schema::Schema;
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Passport Processing.
+///
+/// Options:
+/// * `schema` - a file with the fields that passports must have, instead of the ones from the
+///   puzzle. See `passport.schema` for the format.
+/// * `export` - a path to write every passport to after running the parts, with its fields,
+///   whether it is valid, and any duplicated or unknown fields. Files ending in `.csv` are written
+///   as CSV, and anything else as JSON lines.
+pub struct Day04 {
+    pub schema: Schema,
+    pub export: Option<String>,
+}
+
+impl Default for Day04 {
+    fn default() -> Self {
+        Day04 {
+            schema: Schema::passport(),
+            export: None,
+        }
+    }
+}
+
+impl Solution for Day04 {
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        Ok(part1(&parse(text)?, &self.schema))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        Ok(part2(&parse(text)?, &self.schema))
+    }
+
+    fn render(&self, text: &str, _console: &mut dyn Write) -> Result<()> {
+        if let Some(path) = &self.export {
+            self.export(path, &parse(text)?).map_err(|e| format!("{}: {}", path, e))?;
+        }
+        Ok(())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "schema" => {
+                let text = input::read_all(&mut File::open(value).map_err(|e| format!("{}: {}", value, e))?)?;
+                self.schema = Schema::parse(&text).map_err(|e| format!("{}: {}", value, e))?;
+            }
+            "export" => self.export = Some(value.to_string()),
+            _ => return Err(format!("day 4 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+impl Day04 {
+    fn export(&self, path: &str, passports: &[Passport]) -> Result<()> {
+        let checked: Vec<_> = passports.iter().map(|passport| export::Checked::new(passport, &self.schema)).collect();
+        let mut out = BufWriter::new(File::create(path)?);
+        if path.ends_with(".csv") {
+            export::write_csv(&mut out, &self.schema, &checked)?;
+        } else {
+            export::write_json_lines(&mut out, &checked)?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Reads passports, which are separated by blank lines.
+fn parse(text: &str) -> Result<Vec<Passport<'_>>, ParseError> {
+    input::records(text).iter().map(Passport::parse).collect()
+}
+
+/// A passport's fields, in the order they were written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Passport<'a> {
+    /// The line number that the passport starts on.
+    pub line: usize,
+    pub fields: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Passport<'a> {
+    /// Parses a record of whitespace-separated `key:value` fields.
+    pub fn parse(record: &Record<'a>) -> Result<Self, ParseError> {
+        let mut fields = Vec::new();
+        for (number, line) in (record.line..).zip(record.lines.iter().copied()) {
+            for field in line.split_whitespace() {
+                let mut split = field.splitn(2, ':');
+                match (split.next(), split.next()) {
+                    (Some(key), Some(value)) if !key.is_empty() => fields.push((key, value)),
+                    _ => {
+                        let offset = field.as_ptr() as usize - line.as_ptr() as usize;
+                        return Err(ParseError::syntax(Position::in_line(number, line, offset), field, "a field like `key:value`"));
+                    }
+                }
+            }
+        }
+        Ok(Passport {
+            line: record.line,
+            fields,
+        })
+    }
+
+    /// Gets the value of a field, or `None` if the passport doesn't have it. If it has the field
+    /// more than once, this is the first value.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|&(_, value)| value)
+    }
+
+    /// Finds the required fields that this passport doesn't have.
+    pub fn missing(&self, schema: &Schema) -> Vec<PassportError> {
+        schema.required()
+            .filter(|field| self.get(field).is_none())
+            .map(|field| PassportError::Missing { field: field.to_string() })
+            .collect()
+    }
+
+    /// Finds everything wrong with this passport: missing fields, fields that are given more than
+    /// once, and fields with invalid values. Fields that aren't in the schema are always valid.
+    pub fn validate(&self, schema: &Schema) -> Vec<PassportError> {
+        let mut errors = self.missing(schema);
+        for (i, &(key, value)) in self.fields.iter().enumerate() {
+            match self.fields[..i].iter().filter(|(k, _)| *k == key).count() {
+                0 => {
+                    let check = schema.get(key).map_or(Ok(()), |rule| rule.validator.check(value));
+                    if let Err(reason) = check {
+                        errors.push(PassportError::Invalid {
+                            field: key.to_string(),
+                            value: value.to_string(),
+                            reason,
+                        });
+                    }
+                }
+                // only report each duplicated key once
+                1 => errors.push(PassportError::Duplicate { field: key.to_string() }),
+                _ => {}
+            }
+        }
+        errors
+    }
+}
+
+/// A reason that a passport is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PassportError {
+    Missing { field: String },
+    Duplicate { field: String },
+    Invalid { field: String, value: String, reason: String },
+}
+
+impl Display for PassportError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            PassportError::Missing { field } => write!(fmt, "{} is missing", field),
+            PassportError::Duplicate { field } => write!(fmt, "{} is given more than once", field),
+            PassportError::Invalid { field, value, reason } => write!(fmt, "{} {:?} is invalid: {}", field, value, reason),
+        }
+    }
+}
+
+impl std::error::Error for PassportError {}
+
+fn part1(passports: &[Passport], schema: &Schema) -> Answer {
+    let valid = passports.iter().filter(|passport| passport.missing(schema).is_empty()).count();
+    Answer::new(valid).with("passports", passports.len())
+}
+
+fn part2(passports: &[Passport], schema: &Schema) -> Answer {
+    let valid = passports.iter().filter(|passport| passport.validate(schema).is_empty()).count();
+    Answer::new(valid).with("passports", passports.len())
+}
+
+#[test]
+fn test_validate() {
+    let text = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\nhcl:#623a2f\n\n\
+                eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926 ecl:blu\n";
+    let passports = parse(text).unwrap();
+    let schema = Schema::passport();
+    assert!(passports[0].validate(&schema).is_empty());
+    let errors: Vec<_> = passports[1].validate(&schema).iter().map(ToString::to_string).collect();
+    assert_eq!(errors, vec![
+        "eyr \"1972\" is invalid: expected a number from 2020 to 2030",
+        "hgt \"170\" is invalid: expected a number followed by cm or in",
+        "pid \"186cm\" is invalid: expected 9 digits",
+        "ecl is given more than once",
+    ]);
+
+    let err = parse("byr:1920 oops\n").unwrap_err();
+    assert_eq!(err.position(), Position::new(1, 10));
+}
@@ -0,0 +1,226 @@
+//! Descriptions of which fields a document must have, and what their values may be.
+
+use aoc_common::error::{ParseError, Position};
+use aoc_common::input;
+use std::ops::RangeInclusive;
+
+/// The schema of the passports in the puzzle.
+const PASSPORT: &str = include_str!("../passport.schema");
+
+/// The fields of a kind of document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    pub fields: Vec<FieldRule>,
+}
+
+/// What a single field of a document must be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldRule {
+    pub name: String,
+    pub required: bool,
+    pub validator: Validator,
+}
+
+/// A check on the value of a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Validator {
+    Any,
+    Number(RangeInclusive<u64>),
+    /// A four-digit number within a range.
+    Year(RangeInclusive<u64>),
+    /// A number with exactly this many digits, including leading zeroes.
+    Digits(usize),
+    /// A colour like `#1a2b3c`.
+    Colour,
+    Enum(Vec<String>),
+    /// A number followed by a unit, where each unit has its own range.
+    Units(Vec<(String, RangeInclusive<u64>)>),
+}
+
+impl Schema {
+    /// The schema of the passports in the puzzle.
+    pub fn passport() -> Self {
+        // this is checked by the tests
+        Schema::parse(PASSPORT).unwrap()
+    }
+
+    /// Parses a schema with one field per line, like `byr required year 1920..=2002`.
+    ///
+    /// Lines starting with `#` are comments. See `passport.schema` for all of the validators.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut fields = Vec::new();
+        for (number, line) in input::numbered_lines(text) {
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            // every token is a slice of the line, so its offset can be found from where it starts
+            let offset = |token: &str| token.as_ptr() as usize - line.as_ptr() as usize;
+            let syntax = |token: &str, expected: &str| {
+                ParseError::syntax(Position::in_line(number, line, offset(token)), token, expected)
+            };
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() < 3 {
+                return Err(ParseError::syntax(Position::new(number, 1), line, "a field like `byr required year 1920..=2002`"));
+            }
+            if fields.iter().any(|field: &FieldRule| field.name == tokens[0]) {
+                return Err(syntax(tokens[0], "a field that is not already in the schema"));
+            }
+            let required = match tokens[1] {
+                "required" => true,
+                "optional" => false,
+                token => return Err(syntax(token, "required or optional")),
+            };
+            let range = |token: &str| parse_range(token).ok_or_else(|| syntax(token, "a range from low to high, like `1920..=2002`"));
+            let params = &tokens[3..];
+            let validator = match (tokens[2], params) {
+                ("any", []) => Validator::Any,
+                ("number", [r]) => Validator::Number(range(r)?),
+                ("year", [r]) => Validator::Year(range(r)?),
+                ("digits", [n]) => Validator::Digits(input::parse_field(number, line, offset(n), n)?),
+                ("colour", []) => Validator::Colour,
+                ("enum", [words]) => Validator::Enum(words.split(',').map(str::to_string).collect()),
+                ("units", params) if !params.is_empty() && params.len() % 2 == 0 => {
+                    let units = params.chunks(2)
+                        .map(|pair| Ok((pair[0].to_string(), range(pair[1])?)))
+                        .collect::<Result<_, ParseError>>()?;
+                    Validator::Units(units)
+                }
+                (validator, _) => {
+                    return Err(syntax(validator, "a validator like `any`, `number 1..=9`, `year 1920..=2002`, \
+                        `digits 9`, `colour`, `enum amb,blu` or `units cm 150..=193`"))
+                }
+            };
+            fields.push(FieldRule {
+                name: tokens[0].to_string(),
+                required,
+                validator,
+            });
+        }
+        Ok(Schema { fields })
+    }
+
+    /// Gets the rule for a field, or `None` if the schema doesn't know about it.
+    pub fn get(&self, name: &str) -> Option<&FieldRule> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// The names of the fields that must be present.
+    pub fn required(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().filter(|field| field.required).map(|field| field.name.as_str())
+    }
+}
+
+/// Parses a range like `1920..=2002`. Ranges that are empty because they are backwards are not
+/// allowed.
+fn parse_range(s: &str) -> Option<RangeInclusive<u64>> {
+    let mut split = s.splitn(2, "..=");
+    let lo = split.next()?.parse().ok()?;
+    let hi = split.next()?.parse().ok()?;
+    Some(lo..=hi).filter(|range| !range.is_empty())
+}
+
+impl Validator {
+    /// Checks a value, returning why it is invalid if it is.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            Validator::Any => Ok(()),
+            Validator::Number(range) => number_in(value, range),
+            Validator::Year(range) => {
+                digits(value, 4)?;
+                number_in(value, range)
+            }
+            Validator::Digits(count) => digits(value, *count),
+            Validator::Colour => match value.strip_prefix('#') {
+                Some(hex) if hex.len() == 6 && hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) => Ok(()),
+                _ => Err("expected a colour like #1a2b3c".to_string()),
+            },
+            Validator::Enum(words) => {
+                if words.iter().any(|word| word == value) {
+                    Ok(())
+                } else {
+                    Err(format!("expected one of {}", words.join(", ")))
+                }
+            }
+            Validator::Units(units) => {
+                // use the longest unit that matches, so that `cm` wins over `m`
+                let unit = units.iter()
+                    .filter(|(unit, _)| value.ends_with(unit.as_str()))
+                    .max_by_key(|(unit, _)| unit.len());
+                match unit {
+                    Some((unit, range)) => match &value[..value.len() - unit.len()] {
+                        "" => Err(format!("expected a number before {}", unit)),
+                        number => number_in(number, range).map_err(|e| format!("{} {}", e, unit)),
+                    },
+                    None => {
+                        let names: Vec<_> = units.iter().map(|(unit, _)| unit.as_str()).collect();
+                        Err(format!("expected a number followed by {}", names.join(" or ")))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checks for a number that is within the given range.
+fn number_in(value: &str, range: &RangeInclusive<u64>) -> Result<(), String> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("expected a number".to_string());
+    }
+    match value.parse::<u64>() {
+        Ok(n) if range.contains(&n) => Ok(()),
+        _ => Err(format!("expected a number from {} to {}", range.start(), range.end())),
+    }
+}
+
+fn digits(value: &str, count: usize) -> Result<(), String> {
+    if value.len() == count && value.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(format!("expected {} digits", count))
+    }
+}
+
+#[test]
+fn test_passport_schema() {
+    let schema = Schema::passport();
+    let check = |field: &str, value: &str| schema.get(field).unwrap().validator.check(value);
+    assert_eq!(schema.required().collect::<Vec<_>>(), vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]);
+    assert_eq!(check("byr", "2002"), Ok(()));
+    assert_eq!(check("byr", "2003"), Err("expected a number from 1920 to 2002".to_string()));
+    assert_eq!(check("byr", "02002"), Err("expected 4 digits".to_string()));
+    assert_eq!(check("hgt", "60in"), Ok(()));
+    assert_eq!(check("hgt", "190cm"), Ok(()));
+    assert_eq!(check("hgt", "190in"), Err("expected a number from 59 to 76 in".to_string()));
+    assert_eq!(check("hgt", "190"), Err("expected a number followed by cm or in".to_string()));
+    assert_eq!(check("hgt", "cm"), Err("expected a number before cm".to_string()));
+    assert_eq!(check("hcl", "#123abc"), Ok(()));
+    assert!(check("hcl", "#123abz").is_err());
+    assert!(check("hcl", "123abc").is_err());
+    assert_eq!(check("ecl", "brn"), Ok(()));
+    assert!(check("ecl", "wat").is_err());
+    assert_eq!(check("pid", "000000001"), Ok(()));
+    assert!(check("pid", "0123456789").is_err());
+    assert_eq!(check("cid", "anything"), Ok(()));
+
+    // a unit that ends with another unit
+    let schema = Schema::parse("len required units m 1..=2 cm 100..=200\n").unwrap();
+    let check = |value: &str| schema.get("len").unwrap().validator.check(value);
+    assert_eq!(check("150cm"), Ok(()));
+    assert_eq!(check("2m"), Ok(()));
+    assert_eq!(check("150m"), Err("expected a number from 1 to 2 m".to_string()));
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = Schema::parse("# comment\nage sometimes number 1..=9\n").unwrap_err();
+    assert_eq!(err.position(), Position::new(2, 5));
+    let err = Schema::parse("age optional number 1-9\n").unwrap_err();
+    assert_eq!(err.text(), "1-9");
+    let err = Schema::parse("hgt required units cm\n").unwrap_err();
+    assert_eq!(err.text(), "units");
+    assert!(Schema::parse("age optional\n").is_err());
+    let err = Schema::parse("byr required year 2002..=1920\n").unwrap_err();
+    assert_eq!((err.position(), err.text()), (Position::new(1, 19), "2002..=1920"));
+    let err = Schema::parse("byr required any\n# again\nbyr optional any\n").unwrap_err();
+    assert_eq!((err.position(), err.text()), (Position::new(3, 1), "byr"));
+}
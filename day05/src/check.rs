@@ -0,0 +1,98 @@
+//! Checking that a list of boarding passes makes sense before using it.
+
+use crate::{BoardingPass, Layout};
+use aoc_common::error::ParseError;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// Something wrong with a boarding pass in a list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The code could not be read, including codes that are too long or short for the plane.
+    Invalid(ParseError),
+    /// The seat was already given to the boarding pass on an earlier line.
+    Duplicate { line: usize, id: usize, first: usize },
+    /// The seat is outside of the seats that are expected to be on the plane.
+    OutOfRange { line: usize, id: usize, seats: RangeInclusive<usize> },
+}
+
+impl Problem {
+    pub fn line(&self) -> usize {
+        match self {
+            Problem::Invalid(e) => e.position().line,
+            Problem::Duplicate { line, .. } | Problem::OutOfRange { line, .. } => *line,
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Problem::Invalid(e) => write!(fmt, "{}", e),
+            Problem::Duplicate { line, id, first } => {
+                write!(fmt, "line {}: seat {} was already given to line {}", line, id, first)
+            }
+            Problem::OutOfRange { line, id, seats } => {
+                write!(fmt, "line {}: seat {} is outside of seats {}..={}", line, id, seats.start(), seats.end())
+            }
+        }
+    }
+}
+
+/// The boarding passes that could be used from a list, and the problems with the rest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    /// Each good boarding pass with its line number, in the order they were listed.
+    pub passes: Vec<(usize, BoardingPass)>,
+    /// The problems, in the order of their lines.
+    pub problems: Vec<Problem>,
+}
+
+/// Reads a list of boarding passes, keeping the first pass for each seat and, if `seats` is
+/// given, only the passes for seats within it.
+pub fn check(lines: &[(usize, &str)], layout: Layout, seats: Option<&RangeInclusive<usize>>) -> Manifest {
+    let mut passes = Vec::new();
+    let mut problems = Vec::new();
+    // the line that each seat was given on
+    let mut given = vec![None; layout.rows() * layout.cols()];
+    for &(line, code) in lines {
+        let pass = match layout.parse(line, code) {
+            Ok(pass) => pass,
+            Err(e) => {
+                problems.push(Problem::Invalid(e));
+                continue;
+            }
+        };
+        let id = pass.id();
+        if let Some(seats) = seats.filter(|seats| !seats.contains(&id)) {
+            problems.push(Problem::OutOfRange { line, id, seats: seats.clone() });
+        } else if let Some(first) = given[id] {
+            problems.push(Problem::Duplicate { line, id, first });
+        } else {
+            given[id] = Some(line);
+            passes.push((line, pass));
+        }
+    }
+    Manifest { passes, problems }
+}
+
+#[test]
+fn test_check() {
+    let layout: Layout = "2x2".parse().unwrap();
+    let lines = [(1, "FBLR"), (2, "FBXR"), (3, "BBRR"), (4, "FBLR"), (5, "FFLL"), (6, "FBLRR"), (7, "FBLR")];
+    let manifest = check(&lines, layout, Some(&(1..=14)));
+    let ids: Vec<_> = manifest.passes.iter().map(|&(line, pass)| (line, pass.id())).collect();
+    assert_eq!(ids, vec![(1, 5)]);
+    let problems: Vec<_> = manifest.problems.iter().map(ToString::to_string).collect();
+    assert_eq!(problems, vec![
+        "line 2, column 3: expected L or R, but found \"X\"",
+        "line 3: seat 15 is outside of seats 1..=14",
+        "line 4: seat 5 was already given to line 1",
+        "line 5: seat 0 is outside of seats 1..=14",
+        "line 6, column 5: expected a boarding pass of 4 letters, but found \"R\"",
+        "line 7: seat 5 was already given to line 1",
+    ]);
+    assert_eq!(manifest.problems.iter().map(Problem::line).collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7]);
+
+    assert!(check(&lines[2..3], layout, None).problems.is_empty());
+}
@@ -0,0 +1,479 @@
+pub mod check;
+pub mod map;
+
+use aoc_common::error::{ParseError, Position};
+use aoc_common::{input, Answer, Error, Result, Solution, Value};
+use check::Manifest;
+use map::SeatMap;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Binary Boarding.
+///
+/// Options:
+/// * `layout` - the layout of the plane, as the number of row and column letters and optionally
+///   the letters used for the front, back, left and right halves, like `7x3` or `7x3:FBLR` (the
+///   default).
+/// * `map` - a path to draw a map of which seats are taken to, after running the parts.
+/// * `seats` - the range of seat IDs that are expected to be on the plane, like `8..=1015`.
+///   Boarding passes for other seats are a problem.
+/// * `problems` - `error` to fail if any boarding pass can't be read, is for a seat that was
+///   already given out or is outside of `seats` (the default), or `skip` to ignore those boarding
+///   passes and list them in the answer.
+#[derive(Default)]
+pub struct Day05 {
+    pub layout: Layout,
+    pub map: Option<String>,
+    pub seats: Option<RangeInclusive<usize>>,
+    pub problems: Problems,
+}
+
+/// What to do with boarding passes that have problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Problems {
+    #[default]
+    Error,
+    Skip,
+}
+
+impl Solution for Day05 {
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        let manifest = self.check(text)?;
+        Ok(with_problems(part1(&manifest), &manifest))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        let manifest = self.check(text)?;
+        let map = SeatMap::new(self.layout, manifest.passes.iter().map(|&(_, pass)| pass));
+        Ok(with_problems(part2(&map), &manifest))
+    }
+
+    fn render(&self, text: &str, _console: &mut dyn Write) -> Result<()> {
+        if let Some(path) = &self.map {
+            let manifest = self.check(text)?;
+            let map = SeatMap::new(self.layout, manifest.passes.iter().map(|&(_, pass)| pass));
+            fs::write(path, format!("{}\n", map)).map_err(|e| format!("{}: {}", path, e))?;
+        }
+        Ok(())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "layout" => self.layout = value.parse()?,
+            "map" => self.map = Some(value.to_string()),
+            "seats" => {
+                let range = value.split_once("..=")
+                    .and_then(|(lo, hi)| Some(lo.parse().ok()?..=hi.parse().ok()?))
+                    .filter(|range: &RangeInclusive<usize>| !range.is_empty());
+                self.seats = Some(range.ok_or_else(|| format!("invalid seats {:?} (expected something like 8..=1015)", value))?);
+            }
+            "problems" => {
+                self.problems = match value {
+                    "error" => Problems::Error,
+                    "skip" => Problems::Skip,
+                    _ => return Err(format!("invalid problems {:?} (expected error or skip)", value).into()),
+                }
+            }
+            _ => return Err(format!("day 5 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+impl Day05 {
+    /// Reads the boarding passes, failing if any have problems unless they should be skipped.
+    fn check(&self, text: &str) -> Result<Manifest> {
+        let manifest = check::check(&input::numbered_lines(text), self.layout, self.seats.as_ref());
+        if self.problems == Problems::Error && !manifest.problems.is_empty() {
+            let problems: Vec<_> = manifest.problems.iter().map(ToString::to_string).collect();
+            return Err(problems.join("\n").into());
+        }
+        Ok(manifest)
+    }
+}
+
+/// Lists any skipped boarding passes in an answer.
+fn with_problems(answer: Answer, manifest: &Manifest) -> Answer {
+    if manifest.problems.is_empty() {
+        return answer;
+    }
+    let problems: Vec<_> = manifest.problems.iter().map(ToString::to_string).collect();
+    answer.with("problems", problems)
+}
+
+/// The most letters that a boarding pass can have, so that the seats of a plane fit in memory.
+const MAX_BITS: usize = 24;
+
+/// The seats of a plane and how boarding passes pick one.
+///
+/// The first `row_bits` letters of a code pick the row, where the `front` letter means the front
+/// half of the remaining rows and the `back` letter means the back half. The next `col_bits`
+/// letters pick the column in the same way, using the `left` and `right` letters. The seat ID is
+/// the row times the number of columns, plus the column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Layout {
+    pub row_bits: usize,
+    pub col_bits: usize,
+    pub front: char,
+    pub back: char,
+    pub left: char,
+    pub right: char,
+}
+
+impl Layout {
+    /// The plane from the puzzle, with 128 rows of 8 seats and codes like `FBFBBFFRLR`.
+    pub const STANDARD: Layout = Layout {
+        row_bits: 7,
+        col_bits: 3,
+        front: 'F',
+        back: 'B',
+        left: 'L',
+        right: 'R',
+    };
+
+    pub fn rows(self) -> usize {
+        1 << self.row_bits
+    }
+
+    pub fn cols(self) -> usize {
+        1 << self.col_bits
+    }
+
+    /// How many letters a boarding pass has.
+    pub fn code_len(self) -> usize {
+        self.row_bits + self.col_bits
+    }
+
+    /// Makes a boarding pass for a seat, or returns `None` if it is not on the plane.
+    pub fn pass(self, row: usize, col: usize) -> Option<BoardingPass> {
+        if row < self.rows() && col < self.cols() {
+            Some(BoardingPass { row, col, layout: self })
+        } else {
+            None
+        }
+    }
+
+    /// Makes a boarding pass from a seat ID, or returns `None` if it is not on the plane.
+    pub fn pass_from_id(self, id: usize) -> Option<BoardingPass> {
+        self.pass(id >> self.col_bits, id & (self.cols() - 1))
+    }
+
+    /// Reads a boarding pass code for this plane.
+    pub fn read(self, code: &str) -> Result<BoardingPass, PassError> {
+        let mut row = 0;
+        let mut col = 0;
+        let mut len = 0;
+        for (index, c) in code.chars().enumerate() {
+            // each letter is one bit of the row or column, from the most significant
+            let (zero, one) = self.letters(index).ok_or(PassError::Letter { index, found: c, expected: None })?;
+            let bit = if c == zero {
+                0
+            } else if c == one {
+                1
+            } else {
+                return Err(PassError::Letter { index, found: c, expected: Some((zero, one)) });
+            };
+            if index < self.row_bits {
+                row = row << 1 | bit;
+            } else {
+                col = col << 1 | bit;
+            }
+            len += 1;
+        }
+        if len != self.code_len() {
+            return Err(PassError::Length { found: len, expected: self.code_len() });
+        }
+        Ok(BoardingPass { row, col, layout: self })
+    }
+
+    /// Parses a boarding pass code for this plane, with errors pointing at the given line number.
+    pub fn parse(self, number: usize, code: &str) -> Result<BoardingPass, ParseError> {
+        self.read(code).map_err(|e| match e {
+            PassError::Letter { index, found, .. } => {
+                ParseError::syntax(Position::new(number, index + 1), found.to_string(), e.expected())
+            }
+            PassError::Length { .. } => ParseError::syntax(Position::new(number, 1), code, e.expected()),
+        })
+    }
+
+    /// The letters for a 0 bit and a 1 bit at a place in a code, or `None` if the code should have
+    /// ended before it.
+    fn letters(self, index: usize) -> Option<(char, char)> {
+        if index < self.row_bits {
+            Some((self.front, self.back))
+        } else if index < self.code_len() {
+            Some((self.left, self.right))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout::STANDARD
+    }
+}
+
+impl FromStr for Layout {
+    type Err = Error;
+
+    /// Parses a layout like `7x3` or `7x3:FBLR`.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || format!("invalid layout {:?} (expected something like 7x3 or 7x3:FBLR)", s);
+        let mut split = s.splitn(2, ':');
+        let mut bits = split.next().unwrap().splitn(2, 'x');
+        let mut layout = match (bits.next(), bits.next()) {
+            (Some(rows), Some(cols)) => Layout {
+                row_bits: rows.trim().parse().map_err(|_| invalid())?,
+                col_bits: cols.trim().parse().map_err(|_| invalid())?,
+                ..Layout::STANDARD
+            },
+            _ => return Err(invalid().into()),
+        };
+        if let Some(letters) = split.next() {
+            match letters.trim().chars().collect::<Vec<_>>()[..] {
+                [front, back, left, right] => {
+                    layout.front = front;
+                    layout.back = back;
+                    layout.left = left;
+                    layout.right = right;
+                }
+                _ => return Err(invalid().into()),
+            }
+        }
+        if layout.code_len() > MAX_BITS {
+            return Err(format!("invalid layout {:?} (at most {} letters are allowed)", s, MAX_BITS).into());
+        }
+        if layout.front == layout.back || layout.left == layout.right {
+            return Err(format!("invalid layout {:?} (the letters for each half must be different)", s).into());
+        }
+        Ok(layout)
+    }
+}
+
+/// A seat on a plane, which can be written as a boarding pass code like `FBFBBFFRLR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoardingPass {
+    row: usize,
+    col: usize,
+    layout: Layout,
+}
+
+impl BoardingPass {
+    /// Makes a boarding pass for a seat on the standard plane, or returns `None` if it is not on
+    /// the plane.
+    pub fn new(row: usize, col: usize) -> Option<Self> {
+        Layout::STANDARD.pass(row, col)
+    }
+
+    /// Makes a boarding pass from a seat ID on the standard plane, or returns `None` if it is not
+    /// on the plane.
+    pub fn from_id(id: usize) -> Option<Self> {
+        Layout::STANDARD.pass_from_id(id)
+    }
+
+    /// Parses a boarding pass code for the standard plane, with errors pointing at the given line
+    /// number.
+    pub fn parse(number: usize, code: &str) -> Result<Self, ParseError> {
+        Layout::STANDARD.parse(number, code)
+    }
+
+    pub fn row(self) -> usize {
+        self.row
+    }
+
+    pub fn col(self) -> usize {
+        self.col
+    }
+
+    pub fn layout(self) -> Layout {
+        self.layout
+    }
+
+    /// The seat ID, which is the row times the number of columns, plus the column.
+    pub fn id(self) -> usize {
+        self.row << self.layout.col_bits | self.col
+    }
+}
+
+/// Why a boarding pass code could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PassError {
+    /// A letter was not one of the two allowed at its place in the code, or was past the end of
+    /// the code.
+    Letter { index: usize, found: char, expected: Option<(char, char)> },
+    /// The code had the wrong number of letters.
+    Length { found: usize, expected: usize },
+}
+
+impl PassError {
+    fn expected(&self) -> String {
+        match self {
+            PassError::Letter { expected: Some((zero, one)), .. } => format!("{} or {}", zero, one),
+            PassError::Letter { index, .. } => format!("a boarding pass of {} letters", index),
+            PassError::Length { expected, .. } => format!("a boarding pass of {} letters", expected),
+        }
+    }
+}
+
+impl Display for PassError {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            PassError::Letter { index, found, .. } => {
+                write!(fmt, "letter {}: expected {}, but found {:?}", index + 1, self.expected(), found)
+            }
+            PassError::Length { found, .. } => write!(fmt, "expected {}, but found {} letters", self.expected(), found),
+        }
+    }
+}
+
+impl std::error::Error for PassError {}
+
+impl FromStr for BoardingPass {
+    type Err = PassError;
+
+    /// Reads a boarding pass code for the standard plane.
+    fn from_str(code: &str) -> Result<Self, PassError> {
+        Layout::STANDARD.read(code)
+    }
+}
+
+impl Display for BoardingPass {
+    /// Writes the boarding pass code, like `FBFBBFFRLR`.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let layout = self.layout;
+        for bit in (0..layout.row_bits).rev() {
+            write!(fmt, "{}", if self.row >> bit & 1 == 1 { layout.back } else { layout.front })?;
+        }
+        for bit in (0..layout.col_bits).rev() {
+            write!(fmt, "{}", if self.col >> bit & 1 == 1 { layout.right } else { layout.left })?;
+        }
+        Ok(())
+    }
+}
+
+fn part1(manifest: &Manifest) -> Answer {
+    let max = manifest.passes.iter().map(|(_, pass)| pass.id()).max().unwrap_or(0);
+    Answer::new(max)
+}
+
+/// Finds our seat, along with statistics about which seats are taken. If there is no seat that
+/// could be ours, the answer is `none` and the statistics are still given, to help find out why.
+fn part2(map: &SeatMap) -> Answer {
+    let gaps = map.gaps();
+    // each row on the plane with how many of its seats are taken
+    let taken_per_row = map.taken_per_row();
+    let occupancy: Vec<_> = map.rows()
+        .into_iter()
+        .flatten()
+        .map(|row| Value::Map(vec![("row".to_string(), row.into()), ("taken".to_string(), taken_per_row[row].into())]))
+        .collect();
+
+    // our seat is the empty one where the seats on either side of it are taken
+    let seat = gaps.iter().copied().find(|&id| map.is_taken(id - 1) && map.is_taken(id + 1));
+    let answer = match seat {
+        Some(seat) => Answer::new(seat),
+        None => Answer::new("none").with("missing_seat", "Could not find an empty seat between two taken seats."),
+    };
+    answer
+        .with("empty_seats", gaps)
+        .with("empty_rows", map.empty_rows())
+        .with("taken_per_row", occupancy)
+}
+
+// some sanity checks ...
+
+#[test]
+fn test_rows_and_cols() {
+    let row = |code: &str| code.parse::<BoardingPass>().unwrap().row();
+    assert_eq!(row("FBFBBFFRLR"), 44);
+    assert_eq!(row("BFFFBBFRRR"), 70);
+    assert_eq!(row("FFFBBBFRRR"), 14);
+    assert_eq!(row("BBFFBBFRLL"), 102);
+    let col = |code: &str| code.parse::<BoardingPass>().unwrap().col();
+    assert_eq!(col("FFFFFFFRLR"), 5);
+    assert_eq!(col("FFFFFFFRRR"), 7);
+    assert_eq!(col("FFFFFFFRLL"), 4);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(BoardingPass::parse(1, "FBFBBFFRLR").ok(), BoardingPass::new(44, 5));
+    assert_eq!(BoardingPass::parse(3, "FBFBXFFRLR").unwrap_err().position(), Position::new(3, 5));
+    assert_eq!(BoardingPass::parse(1, "FBFBBFFRL").unwrap_err().position(), Position::new(1, 1));
+    assert_eq!(BoardingPass::parse(1, "FBFBBFFRLRR").unwrap_err().position(), Position::new(1, 11));
+    assert_eq!(
+        "FBFBBFFRRF".parse::<BoardingPass>().unwrap_err().to_string(),
+        "letter 10: expected L or R, but found 'F'"
+    );
+    assert_eq!(
+        "FBF".parse::<BoardingPass>().unwrap_err().to_string(),
+        "expected a boarding pass of 10 letters, but found 3 letters"
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let pass: BoardingPass = "BBFFBBFRLL".parse().unwrap();
+    assert_eq!(pass.id(), 820);
+    assert_eq!(BoardingPass::from_id(820), Some(pass));
+    assert_eq!(BoardingPass::new(102, 4), Some(pass));
+    assert_eq!(pass.to_string(), "BBFFBBFRLL");
+    for id in 0..1024 {
+        let pass = BoardingPass::from_id(id).unwrap();
+        assert_eq!(pass.to_string().parse::<BoardingPass>(), Ok(pass));
+    }
+    assert_eq!(BoardingPass::from_id(1024), None);
+    assert_eq!(BoardingPass::new(0, 8), None);
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!("7x3:FBLR".parse::<Layout>().unwrap(), Layout::STANDARD);
+    assert_eq!("7x3".parse::<Layout>().unwrap(), Layout::STANDARD);
+    assert!("7x3:FFLR".parse::<Layout>().is_err());
+    assert!("7x3:FBL".parse::<Layout>().is_err());
+    assert!("20x20".parse::<Layout>().is_err());
+
+    // a small plane with 4 rows of 4 seats, lettered from the bottom up and out to in
+    let layout: Layout = "2x2:DUOI".parse().unwrap();
+    assert_eq!((layout.rows(), layout.cols(), layout.code_len()), (4, 4, 4));
+    let pass = layout.read("UDIO").unwrap();
+    assert_eq!((pass.row(), pass.col(), pass.id()), (2, 2, 10));
+    assert_eq!(pass.to_string(), "UDIO");
+    assert_eq!(layout.pass_from_id(10), Some(pass));
+    assert_eq!(layout.pass_from_id(16), None);
+    assert_eq!(
+        layout.read("UDIOI").unwrap_err().to_string(),
+        "letter 5: expected a boarding pass of 4 letters, but found 'I'"
+    );
+    assert_eq!(layout.read("UDIR").unwrap_err().to_string(), "letter 4: expected O or I, but found 'R'");
+}
+
+#[test]
+fn test_part2() {
+    let layout: Layout = "2x2".parse().unwrap();
+    let passes = [2, 3, 5, 6, 13].iter().map(|&id| layout.pass_from_id(id).unwrap());
+    let answer = part2(&SeatMap::new(layout, passes));
+    assert_eq!(answer.value, 4.into());
+    assert_eq!(answer.detail("missing_seat"), None);
+    assert_eq!(answer.detail("empty_rows"), Some(&vec![2].into()));
+    let taken_per_row = answer.detail("taken_per_row").unwrap().to_string();
+    assert_eq!(taken_per_row, "[{row: 0, taken: 2}, {row: 1, taken: 2}, {row: 2, taken: 0}, {row: 3, taken: 1}]");
+
+    // without an empty seat between two taken ones, the statistics are still given
+    let passes = [2, 3, 6, 7].iter().map(|&id| layout.pass_from_id(id).unwrap());
+    let answer = part2(&SeatMap::new(layout, passes));
+    assert_eq!(answer.value, "none".into());
+    assert!(answer.detail("missing_seat").is_some());
+    assert_eq!(answer.detail("empty_seats"), Some(&vec![4, 5].into()));
+    assert_eq!(answer.detail("taken_per_row").unwrap().to_string(), "[{row: 0, taken: 2}, {row: 1, taken: 2}]");
+}
@@ -0,0 +1,126 @@
+//! A map of which seats on a plane are taken, for checking that a manifest makes sense.
+
+use crate::{BoardingPass, Layout};
+use aoc_common::Grid;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// Which seats on a plane are taken.
+///
+/// The seats with IDs before the first taken seat or after the last one are assumed to be missing
+/// from the plane, as the puzzle says some seats at the very front and back are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeatMap {
+    layout: Layout,
+    taken: Grid<bool>,
+    /// The IDs of the first and last taken seats, or `None` if no seats are taken.
+    range: Option<(usize, usize)>,
+}
+
+impl SeatMap {
+    pub fn new(layout: Layout, passes: impl IntoIterator<Item = BoardingPass>) -> Self {
+        let rows = vec![vec![false; layout.cols()]; layout.rows()];
+        let mut taken = Grid::from_rows(rows).unwrap();
+        let mut range: Option<(usize, usize)> = None;
+        for pass in passes {
+            *taken.get_mut(pass.col(), pass.row()).unwrap() = true;
+            let id = pass.id();
+            range = Some(match range {
+                Some((first, last)) => (first.min(id), last.max(id)),
+                None => (id, id),
+            });
+        }
+        SeatMap { layout, taken, range }
+    }
+
+    pub fn is_taken(&self, id: usize) -> bool {
+        let cols = self.layout.cols();
+        self.taken.get(id % cols, id / cols).copied().unwrap_or(false)
+    }
+
+    /// Whether a seat is on the plane, i.e. it is between the first and last taken seats.
+    pub fn exists(&self, id: usize) -> bool {
+        self.range.is_some_and(|(first, last)| id >= first && id <= last)
+    }
+
+    /// The IDs of the first and last taken seats, or `None` if no seats are taken.
+    pub fn range(&self) -> Option<(usize, usize)> {
+        self.range
+    }
+
+    /// The rows from the first taken seat to the last one, or `None` if no seats are taken.
+    pub fn rows(&self) -> Option<RangeInclusive<usize>> {
+        let cols = self.layout.cols();
+        self.range.map(|(first, last)| first / cols..=last / cols)
+    }
+
+    /// How many seats are taken in each row.
+    pub fn taken_per_row(&self) -> Vec<usize> {
+        self.taken.rows().map(|row| row.iter().filter(|&&taken| taken).count()).collect()
+    }
+
+    /// The rows between the first and last taken seats where no seats are taken.
+    pub fn empty_rows(&self) -> Vec<usize> {
+        let taken_per_row = self.taken_per_row();
+        self.rows()
+            .into_iter()
+            .flatten()
+            .filter(|&row| taken_per_row[row] == 0)
+            .collect()
+    }
+
+    /// The IDs of every seat between the first and last taken seats that isn't taken.
+    pub fn gaps(&self) -> Vec<usize> {
+        match self.range {
+            Some((first, last)) => (first..=last).filter(|&id| !self.is_taken(id)).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl Display for SeatMap {
+    /// Draws each row of the plane, with `#` for a taken seat, `.` for an empty one and `-` for one
+    /// that is missing, followed by how many seats in the row are taken.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        let cols = self.layout.cols();
+        let width = (self.layout.rows() - 1).to_string().len();
+        let taken_per_row = self.taken_per_row();
+        for (row, &count) in taken_per_row.iter().enumerate() {
+            if row > 0 {
+                writeln!(fmt)?;
+            }
+            write!(fmt, "{:>width$} ", row, width = width)?;
+            for col in 0..cols {
+                let id = row * cols + col;
+                let c = if self.is_taken(id) {
+                    '#'
+                } else if self.exists(id) {
+                    '.'
+                } else {
+                    '-'
+                };
+                write!(fmt, "{}", c)?;
+            }
+            write!(fmt, " {}/{}", count, cols)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_seat_map() {
+    let layout: Layout = "2x2".parse().unwrap();
+    let ids = [2, 3, 5, 6, 13];
+    let map = SeatMap::new(layout, ids.iter().map(|&id| layout.pass_from_id(id).unwrap()));
+    assert_eq!(map.range(), Some((2, 13)));
+    assert_eq!(map.rows(), Some(0..=3));
+    assert_eq!(map.taken_per_row(), vec![2, 2, 0, 1]);
+    assert_eq!(map.empty_rows(), vec![2]);
+    assert_eq!(map.gaps(), vec![4, 7, 8, 9, 10, 11, 12]);
+    assert_eq!(map.to_string(), "0 --## 2/4\n1 .##. 2/4\n2 .... 0/4\n3 .#-- 1/4");
+
+    let empty = SeatMap::new(layout, Vec::new());
+    assert!(empty.gaps().is_empty());
+    assert!(empty.empty_rows().is_empty());
+    assert_eq!(empty.rows(), None);
+}
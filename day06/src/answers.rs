@@ -0,0 +1,156 @@
+//! Sets of questions answered "yes", and ways to combine the answers of a group.
+
+use aoc_common::error::{ParseError, Position};
+use aoc_common::input::Record;
+use aoc_common::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The questions `a` to `z` that someone answered "yes" to, with one bit per question.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Answers(u32);
+
+impl Answers {
+    /// Every question.
+    pub const ALL: Answers = Answers((1 << 26) - 1);
+
+    /// Reads the questions that someone answered, like `abc`. Whitespace is ignored.
+    pub fn parse(number: usize, line: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        for (offset, c) in line.char_indices() {
+            match c {
+                'a'..='z' => answers.0 |= 1 << (c as u32 - 'a' as u32),
+                _ if c.is_whitespace() => {}
+                _ => return Err(ParseError::syntax(Position::in_line(number, line, offset), c.to_string(), "a question from a to z")),
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn contains(self, question: char) -> bool {
+        question.is_ascii_lowercase() && self.0 >> (question as u32 - 'a' as u32) & 1 == 1
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Answers) -> Answers {
+        Answers(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Answers) -> Answers {
+        Answers(self.0 & other.0)
+    }
+
+    pub fn symmetric_difference(self, other: Answers) -> Answers {
+        Answers(self.0 ^ other.0)
+    }
+
+    /// The questions, in alphabetical order.
+    pub fn questions(self) -> impl Iterator<Item = char> {
+        ('a'..='z').filter(move |&c| self.contains(c))
+    }
+}
+
+impl Display for Answers {
+    /// Writes the questions in alphabetical order, like `abc`.
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        self.questions().try_for_each(|c| write!(fmt, "{}", c))
+    }
+}
+
+/// Reads the answers of each person in a group.
+pub fn parse_group(record: &Record) -> Result<Vec<Answers>, ParseError> {
+    (record.line..)
+        .zip(record.lines.iter())
+        .map(|(number, line)| Answers::parse(number, line))
+        .collect()
+}
+
+/// A way to combine the answers of everyone in a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// The questions that anyone answered.
+    Union,
+    /// The questions that everyone answered.
+    Intersection,
+    /// The questions that an odd number of people answered, which for two people is the
+    /// questions that only one of them answered.
+    SymmetricDifference,
+    /// The questions that at least this many people answered.
+    AtLeast(usize),
+}
+
+impl Aggregate {
+    pub fn apply(self, group: &[Answers]) -> Answers {
+        match self {
+            Aggregate::Union => group.iter().fold(Answers::default(), |acc, &answers| acc.union(answers)),
+            Aggregate::Intersection => group.iter().fold(Answers::ALL, |acc, &answers| acc.intersection(answers)),
+            Aggregate::SymmetricDifference => {
+                group.iter().fold(Answers::default(), |acc, &answers| acc.symmetric_difference(answers))
+            }
+            Aggregate::AtLeast(k) => {
+                let mut result = Answers::default();
+                for bit in 0..26 {
+                    let count = group.iter().filter(|answers| answers.0 >> bit & 1 == 1).count();
+                    if count >= k {
+                        result.0 |= 1 << bit;
+                    }
+                }
+                result
+            }
+        }
+    }
+}
+
+impl FromStr for Aggregate {
+    type Err = Error;
+
+    /// Parses `union`, `intersection`, `symmetric-difference` or `at-least:K`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "union" => Ok(Aggregate::Union),
+            "intersection" => Ok(Aggregate::Intersection),
+            "symmetric-difference" => Ok(Aggregate::SymmetricDifference),
+            _ => match s.strip_prefix("at-least:").map(str::parse) {
+                Some(Ok(k)) => Ok(Aggregate::AtLeast(k)),
+                _ => Err(format!(
+                    "invalid aggregate {:?} (expected union, intersection, symmetric-difference or at-least:K)",
+                    s
+                )
+                .into()),
+            },
+        }
+    }
+}
+
+impl Display for Aggregate {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        match self {
+            Aggregate::Union => write!(fmt, "union"),
+            Aggregate::Intersection => write!(fmt, "intersection"),
+            Aggregate::SymmetricDifference => write!(fmt, "symmetric-difference"),
+            Aggregate::AtLeast(k) => write!(fmt, "at-least:{}", k),
+        }
+    }
+}
+
+#[test]
+fn test_aggregate() {
+    let group: Vec<_> = ["abc", "abd", "ae"].iter().map(|line| Answers::parse(1, line).unwrap()).collect();
+    let apply = |aggregate: &str| aggregate.parse::<Aggregate>().unwrap().apply(&group).to_string();
+    assert_eq!(apply("union"), "abcde");
+    assert_eq!(apply("intersection"), "a");
+    assert_eq!(apply("symmetric-difference"), "acde");
+    assert_eq!(apply("at-least:2"), "ab");
+    assert_eq!(apply("at-least:0"), "abcdefghijklmnopqrstuvwxyz");
+    assert!("at-least:x".parse::<Aggregate>().is_err());
+
+    let err = Answers::parse(4, "ab C").unwrap_err();
+    assert_eq!(err.position(), Position::new(4, 4));
+}
@@ -0,0 +1,49 @@
+pub mod answers;
+
+use answers::{Aggregate, Answers};
+use aoc_common::error::ParseError;
+use aoc_common::{input, Answer, Result, Solution};
+
+/// Custom Customs.
+///
+/// Options:
+/// * `aggregate` - how to combine the answers of each group in both parts, instead of `union` for
+///   part 1 and `intersection` for part 2. This can also be `symmetric-difference`, or
+///   `at-least:K` for the questions that at least `K` people in the group answered.
+#[derive(Default)]
+pub struct Day06 {
+    pub aggregate: Option<Aggregate>,
+}
+
+impl Solution for Day06 {
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        Ok(count(&parse(text)?, self.aggregate.unwrap_or(Aggregate::Union)))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        Ok(count(&parse(text)?, self.aggregate.unwrap_or(Aggregate::Intersection)))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "aggregate" => self.aggregate = Some(value.parse()?),
+            _ => return Err(format!("day 6 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+/// Reads the answers of each group, which are separated by blank lines.
+fn parse(text: &str) -> Result<Vec<Vec<Answers>>, ParseError> {
+    input::records(text).iter().map(answers::parse_group).collect()
+}
+
+/// Sums the number of questions for each group after combining their answers.
+fn count(groups: &[Vec<Answers>], aggregate: Aggregate) -> Answer {
+    let count: usize = groups.iter().map(|group| aggregate.apply(group).len()).sum();
+    Answer::new(count).with("groups", groups.len())
+}
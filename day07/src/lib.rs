@@ -0,0 +1,93 @@
+use aoc_common::error::{ParseError, Position};
+use aoc_common::{input, Answer, Result, Solution};
+use regex::Regex;
+use petgraph::{Direction, Directed, graphmap::GraphMap};
+use std::collections::HashSet;
+
+/// Handy Haversacks.
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        let lines = input::numbered_lines(text);
+        let graph = parse_rules(&lines)?;
+        Ok(part1(&graph))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        let lines = input::numbered_lines(text);
+        let graph = parse_rules(&lines)?;
+        Ok(part2(&graph))
+    }
+}
+
+fn part1(graph: &GraphMap<&str, usize, Directed>) -> Answer {
+    // find the "shiny gold" bag and walk up the tree
+    fn find_ancestors<'a>(start: &'a str, graph: &GraphMap<&'a str, usize, Directed>) -> HashSet<&'a str> {
+        graph.neighbors_directed(start, Direction::Incoming)
+            .map(|n| (n, find_ancestors(n, graph)))
+            .fold(HashSet::new(), |mut acc, (n, ancestors)| {
+                acc.insert(n);
+                acc.extend(ancestors);
+                acc
+            })
+    }
+
+    const START: &str = "shiny gold bag";
+
+    let ancestors = find_ancestors(START, graph);
+    Answer::new(ancestors.len())
+        .with("nodes", graph.node_count())
+        .with("edges", graph.edge_count())
+}
+
+fn part2(graph: &GraphMap<&str, usize, Directed>) -> Answer {
+    // find the number of bags that are contained by the shiny gold bag
+
+    const START: &str = "shiny gold bag";
+    fn count_descendants<'a>(start: &'a str, graph: &GraphMap<&'a str, usize, Directed>) -> usize {
+        graph.neighbors(start)
+            .map(|n| graph[(start, n)] * (count_descendants(n, graph) + 1))
+            .sum()
+    }
+
+    let descendants = count_descendants(START, graph);
+    Answer::new(descendants)
+        .with("nodes", graph.node_count())
+        .with("edges", graph.edge_count())
+}
+
+fn parse_rules<'a>(rules: &[(usize, &'a str)]) -> Result<GraphMap<&'a str, usize, Directed>, ParseError> {
+    let mut graph = GraphMap::new();
+    let node_regex = Regex::new(r"^(.+? bag)").unwrap();
+
+    // 1. Create all nodes
+    for &(number, rule) in rules {
+        let caps = node_regex.captures(rule).ok_or_else(|| {
+            ParseError::syntax(Position::new(number, 1), rule, "a rule like `light red bags contain 1 bright white bag.`")
+        })?;
+        let bag_name = caps.get(1).unwrap().as_str();
+        graph.add_node(bag_name);
+    }
+
+    let edge_regex = Regex::new(r"(\d+) (.+? bag)").unwrap();
+    // 2. Create all edges
+    for &(number, rule) in rules {
+        // get the node name again - probably inefficient but who cares
+        let caps = node_regex.captures(rule).unwrap();
+        let container = caps.get(1).unwrap().as_str();
+
+        for caps in edge_regex.captures_iter(rule) {
+            let count = caps.get(1).unwrap();
+            let count: usize = input::parse_field(number, rule, count.start(), count.as_str())?;
+            let contained = caps.get(2).unwrap().as_str();
+            graph.add_edge(container, contained, count);
+        }
+    }
+
+    Ok(graph)
+}
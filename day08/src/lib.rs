@@ -0,0 +1,161 @@
+use aoc_common::error::{ParseError, Position};
+use aoc_common::{input, Answer, Result, Solution};
+use regex::Regex;
+
+/// Handheld Halting.
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        Ok(part1(&parse(text)?))
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        part2(&parse(text)?)
+    }
+}
+
+fn parse(text: &str) -> Result<Vec<OpCode>, ParseError> {
+    input::numbered_lines(text)
+        .into_iter()
+        .map(|(number, line)| OpCode::parse(number, line))
+        .collect()
+}
+
+fn part1(code: &[OpCode]) -> Answer {
+    let mut run = Run::new(code.to_vec());
+    let acc = run.run_without_revisit();
+    Answer::new(acc)
+}
+
+fn part2(code: &[OpCode]) -> Result<Answer> {
+    let mut run = Run::new(code.to_vec());
+    let mut flip_ip = 0; // address that we're flipping the opcode of
+    let acc = loop {
+        if flip_ip >= run.code.len() {
+            return Err("Could not find an instruction to flip that makes the program terminate.".into());
+        }
+
+        // if this is an acc opcode, don't bother flipping it because it is not affected
+        if let OpCode::Acc(_) = run.code[flip_ip] {
+            flip_ip += 1;
+            continue;
+        }
+
+        // try this IP
+        run.code[flip_ip].flip();
+        let acc = run.run_without_revisit();
+
+        // if we reached the end, this is the correct IP
+        if run.ip == run.code.len() {
+            break acc;
+        }
+
+        // otherwise, unflip the last IP and try again
+        run.code[flip_ip].flip();
+        flip_ip += 1;
+    };
+
+    Ok(Answer::new(acc).with("flipped_address", flip_ip))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OpCode {
+    Acc(i64),
+    Jmp(i64),
+    Nop(i64),
+}
+
+impl OpCode {
+    /// Flips this opcode for the purposes of determining a corrupted program.
+    ///
+    /// A single Jmp or Nop may have been corrupted in the supplied program. This function flips
+    /// an instruction for the purposes of determining the issue.
+    fn flip(&mut self) {
+        match *self {
+            OpCode::Jmp(amt) => { *self = OpCode::Nop(amt); }
+            OpCode::Nop(amt) => { *self = OpCode::Jmp(amt); }
+            OpCode::Acc(_) => { /* no-op */ }
+        }
+    }
+
+    /// Parses an opcode from the given line of the program.
+    fn parse(number: usize, line: &str) -> Result<Self, ParseError> {
+        lazy_static::lazy_static! {
+            static ref OP_RE: Regex = Regex::new(r"^(nop|acc|jmp) ([+-]\d+)$").unwrap();
+        };
+        let caps = OP_RE.captures(line).ok_or_else(|| {
+            ParseError::syntax(Position::new(number, 1), line, "an instruction like `nop +0`, `acc -1` or `jmp +4`")
+        })?;
+        let op = caps.get(1).unwrap().as_str();
+        let arg = caps.get(2).unwrap();
+        let arg: i64 = input::parse_field(number, line, arg.start(), arg.as_str())?;
+        match op {
+            "nop" => Ok(OpCode::Nop(arg)),
+            "acc" => Ok(OpCode::Acc(arg)),
+            "jmp" => Ok(OpCode::Jmp(arg)),
+            // the regex only matches the three opcodes above
+            _ => unreachable!()
+        }
+    }
+}
+
+struct Run {
+    hits: Vec<bool>,
+    code: Vec<OpCode>,
+    acc: i64,
+    ip: usize,
+}
+
+impl Run {
+    fn new(code: Vec<OpCode>) -> Self {
+        Run {
+            hits: vec!(false; code.len()),
+            code,
+            acc: 0,
+            ip: 0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.hits = vec!(false; self.code.len());
+        self.acc = 0;
+        self.ip = 0;
+    }
+
+    /// Runs the code without revisiting an address.
+    ///
+    /// Returns the accumulator value before an instruction at an already-visited address is
+    /// run.
+    ///
+    /// This is guaranteed to terminate.
+    fn run_without_revisit(&mut self) -> i64 {
+        self.reset();
+        loop {
+            // if we've reached the end of the program, exit
+            if self.ip >= self.code.len() {
+                break;
+            }
+
+            // if we've hit this address, exit the program
+            if self.hits[self.ip] {
+                break;
+            }
+
+            self.hits[self.ip] = true;
+            let mut next_ip = self.ip + 1;
+
+            match self.code[self.ip] {
+                OpCode::Acc(amt) => { self.acc += amt; }
+                OpCode::Jmp(amt) => { next_ip = (self.ip as i64 + amt) as usize; }
+                OpCode::Nop(_) => { /* nop */ }
+            }
+            self.ip = next_ip;
+        }
+        self.acc
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::{input, Answer, Result, Solution};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+const WINDOW_SIZE: usize = 25;
+
+/// Encoding Error.
+///
+/// Options:
+/// * `window` - the number of preceding numbers that each number may be the sum of (default 25).
+pub struct Day09 {
+    pub window: usize,
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Day09 {
+            window: WINDOW_SIZE,
+        }
+    }
+}
+
+impl Solution for Day09 {
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn part1(&self, text: &str) -> Result<Answer> {
+        // read the text the same way as a stream, so that both report the same details
+        self.part1_streaming(&mut text.as_bytes())
+    }
+
+    fn part2(&self, text: &str) -> Result<Answer> {
+        part2(&parse(text)?, self.window)
+    }
+
+    fn part1_streaming(&self, reader: &mut dyn BufRead) -> Result<Answer> {
+        part1(reader, self.window)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "window" => self.window = value.parse()?,
+            _ => return Err(format!("day 9 has no option {:?}", key).into()),
+        }
+        Ok(())
+    }
+}
+
+fn parse(text: &str) -> Result<Vec<usize>> {
+    Ok(input::parse_lines(text)?)
+}
+
+fn is_valid(num: usize, window: &[usize]) -> bool {
+    for i in 0..window.len() {
+        if window[i] > num {
+            continue;
+        }
+        for j in i..window.len() {
+            if window[j] > num {
+                continue;
+            }
+            if window[i] + window[j] == num {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Finds the first number that is not the sum of two of the numbers in the window before it.
+fn find_invalid(nums: &[usize], window_size: usize) -> Option<usize> {
+    nums.windows(window_size + 1)
+        .map(|w| (w[window_size], &w[0..window_size]))
+        // check all possible sums for a number
+        .find(|(num, window)| !is_valid(*num, window))
+        .map(|(num, _)| num)
+}
+
+/// Finds the first invalid number in a stream of numbers and their line numbers, keeping only the
+/// window of numbers before it in memory.
+///
+/// Returns the line number and the invalid number. Numbers after it are not read.
+pub fn find_invalid_streaming<I>(nums: I, window_size: usize) -> Result<Option<(usize, usize)>>
+where
+    I: IntoIterator<Item = Result<(usize, usize)>>,
+{
+    let mut window = VecDeque::with_capacity(window_size + 1);
+    for entry in nums {
+        let (line, num) = entry?;
+        if window.len() == window_size {
+            if !is_valid(num, window.make_contiguous()) {
+                return Ok(Some((line, num)));
+            }
+            window.pop_front();
+        }
+        window.push_back(num);
+    }
+    Ok(None)
+}
+
+fn part1(reader: &mut dyn BufRead, window_size: usize) -> Result<Answer> {
+    let (line, invalid) = find_invalid_streaming(input::parse_reader(reader), window_size)?
+        .ok_or("Could not find an invalid number.")?;
+    Ok(Answer::new(invalid).with("line", line))
+}
+
+fn part2(nums: &[usize], window_size: usize) -> Result<Answer> {
+    let invalid = find_invalid(nums, window_size).ok_or("Could not find an invalid number.")?;
+    for len in 2..nums.len() {
+        // try every window of length nums.len() .. 1
+        for window in nums.windows(len) {
+            let sum = window.iter().sum::<usize>();
+            if sum == invalid {
+                let lo = *window.iter().min().unwrap();
+                let hi = *window.iter().max().unwrap();
+                return Ok(Answer::new(lo + hi)
+                    .with("invalid", invalid)
+                    .with("range", window.to_vec()));
+            }
+        }
+    }
+    Err(format!("Could not find a contiguous range that sums to {}.", invalid).into())
+}
+
+#[test]
+fn test_find_invalid_streaming() {
+    let text = include_str!("../example.txt");
+    let nums = parse(text).unwrap();
+    let invalid = find_invalid(&nums, 5).unwrap();
+    let streamed = find_invalid_streaming(input::parse_reader(text.as_bytes()), 5).unwrap();
+    assert_eq!(streamed, Some((15, invalid)));
+    // nothing after the invalid number is read, so garbage there is not an error
+    let text = format!("{}oops\n", text);
+    assert_eq!(find_invalid_streaming(input::parse_reader(text.as_bytes()), 5).unwrap(), Some((15, 127)));
+
+    // both ways of running part 1 give the same answer and details
+    let day = Day09 { window: 5 };
+    assert_eq!(day.part1(&text).unwrap(), day.part1_streaming(&mut text.as_bytes()).unwrap());
+}